@@ -10,140 +10,510 @@ pub fn derive(input: TokenStream) -> TokenStream {
 }
 
 fn derive_impl(input: &Input) -> syn::Item {
+    let ident = &input.ident;
+    let krate = &input.krate;
     let impl_generics = input.impl_generics();
     let ty_generics = input.ty_generics().collect::<Vec<_>>();
-    let where_predicates = input.where_predicates();
+    let self_ty: syn::Type = syn::parse_quote!(#ident<#(#ty_generics,)*>);
 
-    let with_default = syn::parse_quote!(__format::Standard);
+    let Items {
+        where_predicates,
+        decode,
+        encode,
+        fallback,
+        check_inverse_hook,
+    } = match &input.data {
+        Data::Struct(data) => derive_struct(ident, data),
+        Data::Newtype(field) => derive_newtype(ident, field),
+        Data::Enum(data) => derive_enum(ident, data),
+    };
+    let where_predicates = input.where_predicates().chain(where_predicates);
+    let fallback = fallback.iter();
+    let check_inverse_hook = check_inverse_hook.iter();
 
-    match input {
-        Input::StructNamed {
-            split,
-            trailing,
-            ident,
-            fields,
-            ..
-        } => {
-            let where_predicates =
-                where_predicates.chain(fields.iter().map(|FieldNamed { with, ty, .. }| {
-                    let with = with.as_ref().unwrap_or(&with_default);
-                    syn::parse_quote!(#with: __format::Format<'__format, #ty>)
-                }));
+    syn::parse_quote!(
+        const _: () = {
+            use #krate::format as __format;
+            use #krate::format::__private as __private;
+            use ::std as __std;
+            use ::std::fmt as __fmt;
+
+            impl<'__format, #(#impl_generics,)*> __format::Format<'__format, #self_ty> for __format::Standard
+            where
+            #(#where_predicates,)*
+            {
+                fn decode(value: &'__format str) -> Result<#self_ty, __private::Error> {
+                    #decode
+                }
+
+                fn encode(value: &#self_ty, f: &mut __fmt::Formatter<'_>) -> __fmt::Result {
+                    #encode
+                }
 
-            let decode_split: syn::Expr = if let Some(split) = split {
-                syn::parse_quote!(value.split(#split))
+                #(
+                    fn fallback() -> Option<#self_ty> {
+                        #fallback
+                    }
+                )*
+
+                #(
+                    fn check_inverse_hook<'__check_inverse_hook>(value: &'__check_inverse_hook str) -> __private::CheckResult
+                    where
+                    '__check_inverse_hook: '__format,
+                    Self: '__check_inverse_hook,
+                    {
+                        #check_inverse_hook
+                    }
+                )*
+            }
+        };
+    )
+}
+
+/// The parts of the generated `Format` impl that depend on the kind of input.
+struct Items {
+    where_predicates: Vec<syn::WherePredicate>,
+    decode: syn::Block,
+    encode: syn::Block,
+    fallback: Option<syn::Block>,
+    check_inverse_hook: Option<syn::Block>,
+}
+
+fn with_default() -> syn::Type {
+    syn::parse_quote!(__format::Standard)
+}
+
+fn derive_struct(
+    ident: &syn::Ident,
+    DataStruct {
+        split,
+        trailing,
+        fields,
+        extra,
+    }: &DataStruct,
+) -> Items {
+    let with_default = with_default();
+    let trailing = trailing.as_ref().map(syn::LitBool::value).unwrap_or(false);
+
+    let where_predicates = fields
+        .iter()
+        .flat_map(
+            |Field {
+                 with,
+                 default,
+                 omit_default,
+                 ty,
+                 ..
+             }| {
+                let with = with.as_ref().unwrap_or(&with_default);
+                let default = extra.is_some() || matches!(default, Some(DefaultValue::Trait));
+                iter::once::<syn::WherePredicate>(
+                    syn::parse_quote!(#with: __format::Format<'__format, #ty>),
+                )
+                .chain(
+                    default
+                        .then(|| syn::parse_quote!(#ty: __std::default::Default))
+                        .into_iter()
+                        .chain(omit_default.then(|| syn::parse_quote!(#ty: __std::cmp::PartialEq))),
+                )
+            },
+        )
+        .collect();
+
+    let decode_split: syn::Expr = if let Some(split) = split {
+        syn::parse_quote!(__private::Split::new(value, #split, #trailing))
+    } else {
+        syn::parse_quote!(__private::Split::chars(value))
+    };
+    let decode_field_values = fields
+        .iter()
+        .map(|field| -> syn::FieldValue {
+            let Field {
+                with, member, ty, ..
+            } = field;
+            let with = with.as_ref().unwrap_or(&with_default);
+            let error = field.error(syn::parse_quote!(error), syn::parse_quote!(field));
+            let value: syn::Expr = if extra.is_some() {
+                let default = field.default_value();
+                syn::parse_quote!(
+                    match split.next() {
+                        Some(field) => __private::field::<#with, #ty>(field, |error| #error)?,
+                        None => {
+                            defaulted.push(__std::stringify!(#member).into());
+                            #default
+                        }
+                    }
+                )
+            } else if field.default.is_some() {
+                let default = field.default_value();
+                syn::parse_quote!(
+                    match split.next() {
+                        Some(field) => __private::field::<#with, #ty>(field, |error| #error)?,
+                        None => #default,
+                    }
+                )
             } else {
-                syn::parse_quote!(__format::chars(value))
+                let missing = field.error(
+                    syn::parse_quote!(__private::Error::InsufficientData),
+                    syn::parse_quote!(&value[value.len()..]),
+                );
+                syn::parse_quote!(
+                    match split.next() {
+                        Some(field) => __private::field::<#with, #ty>(field, |error| #error)?,
+                        None => __private::recover::<#with, #ty>(Err(#missing))?,
+                    }
+                )
             };
-            let decode_field_values = fields.iter().map(
-                |FieldNamed { with, ident, ty }| -> syn::FieldValue {
-                    let with = with.as_ref().unwrap_or(&with_default);
-                    syn::parse_quote!(
-                        #ident: {
-                            let _span = __tracing::info_span!(__std::stringify!(#ident)).entered();
-                            <#with as __format::Format<'__format, #ty>>::decode(
-                                split.next().ok_or(__error::Error::InsufficientData)?
-                            )?
-                        }
-                    )
-                },
-            );
-
-            let encode_exprs =
-                fields
-                    .iter()
-                    .enumerate()
-                    .flat_map(|(i, FieldNamed { with, ident, ty })| {
-                        let with = with.as_ref().unwrap_or(&with_default);
-                        iter::once(syn::parse_quote!(
-                            <#with as __format::Format<'__format, #ty>>::encode(&value.#ident, f)
-                        ))
-                        .chain(
-                            split
-                                .iter()
-                                .filter(move |_| {
-                                    i + 1 < fields.len()
-                                        || trailing
-                                            .as_ref()
-                                            .map(syn::LitBool::value)
-                                            .unwrap_or(false)
-                                })
-                                .map(|split| -> syn::Expr {
-                                    syn::parse_quote!(__fmt::Display::fmt(&#split, f))
-                                }),
-                        )
-                    });
+            syn::parse_quote!(
+                #member: {
+                    #value
+                }
+            )
+        })
+        .chain(extra.iter().map(|extra| -> syn::FieldValue {
+            syn::parse_quote!(
+                #extra: __format::Extra {
+                    unknown: split.remainder().map(__std::convert::Into::into),
+                    defaulted,
+                    omitted: __std::vec::Vec::new(),
+                }
+            )
+        }));
+    let decode_defaulted = extra
+        .iter()
+        .map(|_| -> syn::Stmt { syn::parse_quote!(let mut defaulted = __std::vec::Vec::new();) });
 
-            let check_inverse_hook_blocks = fields.iter().map(
-                |FieldNamed { with, ident, ty }| -> syn::Block {
+    let encode_split: Option<syn::Stmt> = split
+        .as_ref()
+        .map(|split| syn::parse_quote!(__fmt::Display::fmt(&#split, f)?;));
+    let encode_stmts = fields.iter().enumerate().map(
+        |(
+            i,
+            Field {
+                with,
+                omit_default,
+                member,
+                ty,
+                ..
+            },
+        )|
+         -> syn::Block {
+            let with = with.as_ref().unwrap_or(&with_default);
+            // Fields that were missing are left out again, unless they or the ones after them
+            // have since been set, i.e. no longer encode as their defaults.
+            let skip = extra.iter().map(|extra| -> syn::Stmt {
+                let unchanged = fields[i..].iter().map(|field| -> syn::Expr {
+                    let Field {
+                        with, member, ty, ..
+                    } = field;
                     let with = with.as_ref().unwrap_or(&with_default);
+                    let default = field.default_value();
                     syn::parse_quote!(
-                        {
-                            let _span = __tracing::info_span!(__std::stringify!(#ident)).entered();
-                            __format::check_inverse::<'__format, '__check_inverse_hook, #with, #ty>(
-                                split.next().ok_or(__error::Error::InsufficientData)?
-                            )?;
-                        }
+                        __std::string::ToString::to_string(
+                            &<#with as __format::Format<'__format, #ty>>::display(&value.#member),
+                        ) == __std::string::ToString::to_string(
+                            &<#with as __format::Format<'__format, #ty>>::display(&#default),
+                        )
                     )
-                },
+                });
+                syn::parse_quote!(
+                    if value.#extra.is_omitted(__std::stringify!(#member))
+                        || value.#extra.is_defaulted(__std::stringify!(#member)) #(&& #unchanged)*
+                    {
+                        break 'fields;
+                    }
+                )
+            });
+            let prefix = encode_split.iter().filter(|_| i > 0 && !trailing);
+            let suffix = encode_split.iter().filter(|_| trailing);
+            let encode: syn::Block = syn::parse_quote!({
+                #(#prefix)*
+                <#with as __format::Format<'__format, #ty>>::encode(&value.#member, f)?;
+                #(#suffix)*
+            });
+            if *omit_default {
+                // The fields after this one are omitted when default too, so this one is needed
+                // if any of them is not.
+                let rest = fields[i..].iter().map(|field| -> syn::Expr {
+                    let member = &field.member;
+                    let default = field.default_value();
+                    syn::parse_quote!(!__std::cmp::PartialEq::eq(&value.#member, &#default))
+                });
+                syn::parse_quote!(
+                    {
+                        #(#skip)*
+                        if #(#rest)||* #encode
+                    }
+                )
+            } else {
+                syn::parse_quote!(
+                    {
+                        #(#skip)*
+                        #encode
+                    }
+                )
+            }
+        },
+    );
+    let encode_fields: syn::Stmt = if extra.is_some() {
+        syn::parse_quote!('fields: { #(#encode_stmts)* })
+    } else {
+        syn::parse_quote!({ #(#encode_stmts)* })
+    };
+    let encode_extra = extra.iter().map(|extra| -> syn::Stmt {
+        let prefix = encode_split.iter().filter(|_| !trailing);
+        let suffix = encode_split.iter().filter(|_| trailing);
+        syn::parse_quote!(
+            if let Some(unknown) = &value.#extra.unknown {
+                #(#prefix)*
+                __fmt::Display::fmt(unknown, f)?;
+                #(#suffix)*
+            }
+        )
+    });
+
+    let check_inverse_hook_blocks = fields.iter().map(
+        |Field {
+             with,
+             default,
+             member,
+             ty,
+             ..
+         }|
+         -> syn::Block {
+            let with = with.as_ref().unwrap_or(&with_default);
+            let context: syn::Expr = syn::parse_quote!(
+                |error| __std::format!("{}: {}", __std::stringify!(#member), error)
             );
+            if default.is_some() || extra.is_some() {
+                syn::parse_quote!({
+                    if let Some(value) = split.next() {
+                        __format::check_inverse::<'__format, '__check_inverse_hook, #with, #ty>(value)
+                            .map_err(#context)?;
+                    }
+                })
+            } else {
+                syn::parse_quote!({
+                    let value = split.next().ok_or(__private::Error::InsufficientData)?;
+                    __format::check_inverse::<'__format, '__check_inverse_hook, #with, #ty>(value)
+                        .map_err(#context)?;
+                })
+            }
+        },
+    );
 
-            syn::parse_quote!(
-                const _: () = {
-                    use crate::error as __error;
-                    use crate::format as __format;
-                    #[cfg(test)]
-                    use ::anyhow as __anyhow;
-                    use ::std as __std;
-                    use ::std::fmt as __fmt;
-                    use ::tracing as __tracing;
-
-                    impl<'__format, #(#impl_generics,)*> __format::Format<'__format, #ident<#(#ty_generics,)*>> for __format::Standard
-                    where
-                    #(#where_predicates,)*
-                    {
-                        #[__tracing::instrument(err)]
-                        fn decode(value: &'__format str) -> Result<#ident<#(#ty_generics,)*>, __error::Error> {
-                            let mut split = #decode_split;
-                            Ok(#ident {#(#decode_field_values,)*})
-                        }
+    // Records without an extra field cannot keep unknown data, so it is an error.
+    let decode_leftover: Option<syn::Stmt> = extra.is_none().then(|| {
+        syn::parse_quote!(if let Some(rest) = split.remainder() {
+            __private::leftover(value, rest)?;
+        })
+    });
+    let check_leftover: Option<syn::Stmt> = extra.is_none().then(|| {
+        syn::parse_quote!(if split.remainder().is_some() {
+            return Err(__private::Error::TrailingData.into());
+        })
+    });
 
-                        fn encode(value: &#ident<#(#ty_generics,)*>, f: &mut __fmt::Formatter<'_>) -> __fmt::Result {
-                            #(#encode_exprs?;)*
-                            Ok(())
-                        }
+    Items {
+        where_predicates,
+        decode: syn::parse_quote!({
+            let mut split = #decode_split;
+            #(#decode_defaulted)*
+            let decoded = #ident {#(#decode_field_values,)*};
+            #decode_leftover
+            Ok(decoded)
+        }),
+        encode: syn::parse_quote!({
+            #encode_fields
+            #(#encode_extra)*
+            Ok(())
+        }),
+        fallback: None,
+        check_inverse_hook: Some(syn::parse_quote!({
+            let mut split = #decode_split;
+            #(#check_inverse_hook_blocks)*
+            #check_leftover
+            Ok(())
+        })),
+    }
+}
 
-                        #[cfg(test)]
-                        #[__tracing::instrument(err)]
-                        fn check_inverse_hook<'__check_inverse_hook>(value: &'__check_inverse_hook str) -> __anyhow::Result<()>
-                        where
-                        '__check_inverse_hook: '__format,
-                        Self: '__check_inverse_hook,
-                        {
-                            let mut split = #decode_split;
-                            #(#check_inverse_hook_blocks)*
-                            Ok(())
-                        }
+fn derive_newtype(ident: &syn::Ident, Field { with, ty, .. }: &Field) -> Items {
+    let with = with.clone().unwrap_or_else(with_default);
+    Items {
+        where_predicates: vec![syn::parse_quote!(#with: __format::Format<'__format, #ty>)],
+        decode: syn::parse_quote!({
+            <#with as __format::Format<'__format, #ty>>::decode(value).map(#ident)
+        }),
+        encode: syn::parse_quote!({
+            <#with as __format::Format<'__format, #ty>>::encode(&value.0, f)
+        }),
+        fallback: Some(syn::parse_quote!({
+            <#with as __format::Format<'__format, #ty>>::fallback().map(#ident)
+        })),
+        check_inverse_hook: Some(syn::parse_quote!({
+            __format::check_inverse::<'__format, '__check_inverse_hook, #with, #ty>(value)
+        })),
+    }
+}
+
+fn derive_enum(ident: &syn::Ident, DataEnum { variants, other }: &DataEnum) -> Items {
+    let decode_arms = variants.iter().map(
+        |Variant {
+             ident: variant,
+             code,
+             ..
+         }|
+         -> syn::Arm { syn::parse_quote!(#code => Ok(#ident::#variant),) },
+    );
+    let decode_other: syn::Arm = match other {
+        Some(Other {
+            ident: variant,
+            with,
+            ty,
+            ..
+        }) => {
+            let with = with.clone().unwrap_or_else(with_default);
+            // Only canonical text, so that the other variant never stands for a known code.
+            syn::parse_quote!(
+                _ => {
+                    let decoded = <#with as __format::Format<'__format, #ty>>::decode(value)?;
+                    if <#with as __format::Format<'__format, #ty>>::display(&decoded).to_string() != value {
+                        return Err(__private::Error::InvalidData);
                     }
-                };
+                    Ok(#ident::#variant(decoded))
+                }
             )
         }
+        None => syn::parse_quote!(_ => Err(__private::Error::InvalidData),),
+    };
+
+    let encode_arms = variants
+        .iter()
+        .map(|Variant { ident: variant, code, .. }| -> syn::Arm {
+            syn::parse_quote!(#ident::#variant => f.write_str(#code),)
+        })
+        .chain(other.iter().map(
+            |Other {
+                 ident: variant,
+                 with,
+                 ty,
+                 ..
+             }|
+             -> syn::Arm {
+                let with = with.clone().unwrap_or_else(with_default);
+                syn::parse_quote!(
+                    #ident::#variant(value) => <#with as __format::Format<'__format, #ty>>::encode(value, f),
+                )
+            },
+        ));
+
+    let where_predicates = other
+        .iter()
+        .map(|Other { with, ty, .. }| -> syn::WherePredicate {
+            let with = with.clone().unwrap_or_else(with_default);
+            syn::parse_quote!(#with: __format::Format<'__format, #ty>)
+        })
+        .collect();
+    let fallback = variants
+        .iter()
+        .find(|variant| variant.fallback)
+        .map(|Variant { ident: variant, .. }| syn::parse_quote!({ Some(#ident::#variant) }))
+        .or_else(|| {
+            other.iter().find(|other| other.fallback).map(
+                |Other {
+                     ident: variant,
+                     with,
+                     ty,
+                     ..
+                 }| {
+                    let with = with.clone().unwrap_or_else(with_default);
+                    syn::parse_quote!({
+                        <#with as __format::Format<'__format, #ty>>::fallback().map(#ident::#variant)
+                    })
+                },
+            )
+        });
+
+    Items {
+        where_predicates,
+        decode: syn::parse_quote!({
+            match value {
+                #(#decode_arms)*
+                #decode_other
+            }
+        }),
+        encode: syn::parse_quote!({
+            match value {
+                #(#encode_arms)*
+            }
+        }),
+        fallback,
+        check_inverse_hook: None,
     }
 }
 
-enum Input {
-    StructNamed {
-        split: Option<syn::Expr>,
-        trailing: Option<syn::LitBool>,
+struct Input {
+    /// The path of `cookieclicker_save`, overridden with `#[format(crate = "path")]`.
+    krate: syn::Path,
+    ident: syn::Ident,
+    generics: syn::Generics,
+    data: Data,
+}
+
+enum Data {
+    Struct(DataStruct),
+    /// A tuple struct with a single field and no `split`, encoded as that field.
+    Newtype(Field),
+    Enum(DataEnum),
+}
+
+struct DataStruct {
+    split: Option<syn::Expr>,
+    trailing: Option<syn::LitBool>,
+
+    fields: Vec<Field>,
+    extra: Option<syn::Member>,
+}
+
+struct Field {
+    /// For sequences, the format of the whole sequence, built from `seq` and the element format.
+    with: Option<syn::Type>,
+    flatten: bool,
+    /// The value of the field when it is missing, along with the fields after it.
+    default: Option<DefaultValue>,
+    /// Whether the field is left out on encode when it and the fields after it hold their defaults.
+    omit_default: bool,
+
+    member: syn::Member,
+    ty: syn::Type,
+}
+
+enum DefaultValue {
+    /// `#[format(default)]`, for `Default::default()`
+    Trait,
+    /// `#[format(default = expr)]`
+    Expr(syn::Expr),
+}
 
-        ident: syn::Ident,
-        generics: syn::Generics,
-        fields: Vec<FieldNamed>,
-    },
+struct DataEnum {
+    variants: Vec<Variant>,
+    /// The variant holding values that match no code.
+    other: Option<Other>,
 }
 
-struct FieldNamed {
+struct Variant {
+    /// A string literal; integer codes and discriminants are converted to their decimal text,
+    /// so that only that exact text decodes to the variant.
+    code: syn::LitStr,
+    fallback: bool,
+
+    ident: syn::Ident,
+}
+
+struct Other {
     with: Option<syn::Type>,
+    fallback: bool,
 
     ident: syn::Ident,
     ty: syn::Type,
@@ -153,12 +523,16 @@ impl syn::parse::Parse for Input {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let input = syn::DeriveInput::parse(input)?;
 
+        let mut krate = syn::parse_quote!(::cookieclicker_save);
         let mut split = None;
         let mut trailing = None;
         for attr in &input.attrs {
             if attr.path().is_ident("format") {
                 attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("split") {
+                    if meta.path.is_ident("crate") {
+                        krate = meta.value()?.parse::<syn::LitStr>()?.parse()?;
+                        Ok(())
+                    } else if meta.path.is_ident("split") {
                         split = Some(meta.value()?.parse()?);
                         Ok(())
                     } else if meta.path.is_ident("trailing") {
@@ -171,55 +545,319 @@ impl syn::parse::Parse for Input {
             }
         }
 
-        match input.data {
+        let data = match input.data {
             syn::Data::Struct(syn::DataStruct {
-                fields: syn::Fields::Named(fields),
+                fields: syn::Fields::Unnamed(fields),
+                ..
+            }) if fields.unnamed.len() == 1 && split.is_none() => {
+                let mut fields = parse_fields(syn::Fields::Unnamed(fields))?;
+                let (field, extra) = (fields.0.remove(0), fields.1);
+                if let Some(extra) = extra {
+                    return Err(syn::Error::new(extra.span(), "extra in newtype"));
+                }
+                Data::Newtype(field)
+            }
+            syn::Data::Struct(syn::DataStruct {
+                fields: fields @ (syn::Fields::Named(_) | syn::Fields::Unnamed(_)),
                 ..
             }) => {
-                let fields = fields
-                    .named
-                    .into_iter()
-                    .map(|field| {
-                        let span = field.span();
-
-                        let mut with = None;
-                        for attr in &field.attrs {
-                            if attr.path().is_ident("format") {
-                                attr.parse_nested_meta(|meta| {
-                                    if meta.path.is_ident("with") {
-                                        with = Some(meta.value()?.parse()?);
-                                        Ok(())
-                                    } else {
-                                        Err(meta.error("unknown"))
-                                    }
-                                })?;
-                            }
-                        }
-
-                        Ok(FieldNamed {
-                            with,
-                            ident: field.ident.ok_or(syn::Error::new(span, "missing ident"))?,
-                            ty: field.ty,
-                        })
-                    })
-                    .collect::<syn::Result<_>>()?;
-                Ok(Self::StructNamed {
+                let (fields, extra) = parse_fields(fields)?;
+                Data::Struct(DataStruct {
                     split,
                     trailing,
-                    ident: input.ident,
-                    generics: input.generics,
                     fields,
+                    extra,
                 })
             }
-            _ => Err(syn::Error::new(input.span(), "unimplemented")),
+            syn::Data::Enum(data) => {
+                let mut variants = Vec::new();
+                let mut other = None;
+                // The discriminant of the next variant, while it is known.
+                let mut next = Some(0);
+                for variant in data.variants {
+                    let span = variant.span();
+
+                    let mut code = None;
+                    let mut fallback = false;
+                    let mut is_other = false;
+                    let mut with = None;
+                    for attr in &variant.attrs {
+                        if attr.path().is_ident("format") {
+                            attr.parse_nested_meta(|meta| {
+                                if meta.path.is_ident("code") {
+                                    code = Some(match meta.value()?.parse()? {
+                                        syn::Lit::Str(code) => code,
+                                        syn::Lit::Int(code) => {
+                                            syn::LitStr::new(code.base10_digits(), code.span())
+                                        }
+                                        code => {
+                                            return Err(syn::Error::new(
+                                                code.span(),
+                                                "invalid code",
+                                            ))
+                                        }
+                                    });
+                                    Ok(())
+                                } else if meta.path.is_ident("fallback") {
+                                    fallback = true;
+                                    Ok(())
+                                } else if meta.path.is_ident("other") {
+                                    is_other = true;
+                                    Ok(())
+                                } else if meta.path.is_ident("with") {
+                                    with = Some(meta.value()?.parse()?);
+                                    Ok(())
+                                } else {
+                                    Err(meta.error("unknown"))
+                                }
+                            })?;
+                        }
+                    }
+
+                    match variant.fields {
+                        syn::Fields::Unit if !is_other => {
+                            let discriminant = match &variant.discriminant {
+                                Some((_, expr)) => parse_discriminant(expr),
+                                None => next,
+                            };
+                            next = discriminant.map(|discriminant| discriminant + 1);
+                            let code = match (code, discriminant) {
+                                (Some(code), _) => code,
+                                (None, Some(discriminant)) => {
+                                    syn::LitStr::new(&discriminant.to_string(), span)
+                                }
+                                (None, None) => {
+                                    return Err(syn::Error::new(
+                                        span,
+                                        "code required after a non-literal discriminant",
+                                    ))
+                                }
+                            };
+                            variants.push(Variant {
+                                code,
+                                fallback,
+                                ident: variant.ident,
+                            });
+                        }
+                        syn::Fields::Unnamed(fields) if is_other && fields.unnamed.len() == 1 => {
+                            if other.is_some() {
+                                return Err(syn::Error::new(span, "duplicate other"));
+                            }
+                            other = Some(Other {
+                                with,
+                                fallback,
+                                ident: variant.ident,
+                                ty: fields.unnamed.into_iter().next().unwrap().ty,
+                            });
+                        }
+                        _ => return Err(syn::Error::new(span, "unimplemented")),
+                    }
+                }
+                Data::Enum(DataEnum { variants, other })
+            }
+            _ => return Err(syn::Error::new(input.span(), "unimplemented")),
+        };
+        Ok(Self {
+            krate,
+            ident: input.ident,
+            generics: input.generics,
+            data,
+        })
+    }
+}
+
+/// The value of an integer literal discriminant such as `3` or `-1`.
+fn parse_discriminant(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(value),
+            ..
+        }) => value.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_discriminant(expr).map(|value| -value),
+        syn::Expr::Group(syn::ExprGroup { expr, .. })
+        | syn::Expr::Paren(syn::ExprParen { expr, .. }) => parse_discriminant(expr),
+        _ => None,
+    }
+}
+
+fn parse_fields(fields: syn::Fields) -> syn::Result<(Vec<Field>, Option<syn::Member>)> {
+    let mut extra = None;
+    let mut parsed: Vec<Field> = Vec::new();
+    for (i, field) in fields.into_iter().enumerate() {
+        let span = field.span();
+        let member = match field.ident {
+            Some(ident) => syn::Member::Named(ident),
+            None => syn::Member::Unnamed(syn::Index {
+                index: i as u32,
+                span,
+            }),
+        };
+
+        let mut with = None;
+        let mut flatten = false;
+        let mut default = None;
+        let mut omit_default = false;
+        let mut is_extra = false;
+        let mut seq = None;
+        for attr in &field.attrs {
+            if attr.path().is_ident("format") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("with") {
+                        with = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("flatten") {
+                        flatten = true;
+                        Ok(())
+                    } else if meta.path.is_ident("default") {
+                        default = Some(if meta.input.peek(syn::Token![=]) {
+                            DefaultValue::Expr(meta.value()?.parse()?)
+                        } else {
+                            DefaultValue::Trait
+                        });
+                        Ok(())
+                    } else if meta.path.is_ident("omit_default") {
+                        omit_default = true;
+                        Ok(())
+                    } else if meta.path.is_ident("optional") {
+                        // Shorthand for an `Option` that is `None` when missing.
+                        default = Some(DefaultValue::Trait);
+                        omit_default = true;
+                        Ok(())
+                    } else if meta.path.is_ident("extra") {
+                        is_extra = true;
+                        Ok(())
+                    } else if meta.path.is_ident("seq") {
+                        seq = Some(Seq::parse(&meta)?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unknown"))
+                    }
+                })?;
+            }
+        }
+        if let Some(seq) = seq {
+            with = Some(seq.with(with.unwrap_or_else(with_default))?);
+        }
+        if omit_default && default.is_none() {
+            return Err(syn::Error::new(span, "omit_default without default"));
+        }
+        if let Some(last) = parsed.last() {
+            if last.default.is_some() && default.is_none() && !is_extra {
+                return Err(syn::Error::new(
+                    span,
+                    "required field after defaulted field",
+                ));
+            }
+            if last.omit_default && !omit_default && !is_extra {
+                return Err(syn::Error::new(
+                    span,
+                    "omit_default must be set on the rest",
+                ));
+            }
+        }
+
+        if is_extra {
+            if extra.is_some() {
+                return Err(syn::Error::new(span, "duplicate extra"));
+            }
+            extra = Some(member);
+        } else {
+            parsed.push(Field {
+                with,
+                flatten,
+                default,
+                omit_default,
+                member,
+                ty: field.ty,
+            });
+        }
+    }
+    Ok((parsed, extra))
+}
+
+/// The layout of a sequence field, as in `#[format(seq(split = ':', width = 2, trailing = true))]`.
+/// Without `split`, elements are made of `width` characters.
+struct Seq {
+    split: Option<syn::LitChar>,
+    width: Option<syn::LitInt>,
+    trailing: Option<syn::LitBool>,
+}
+
+impl Seq {
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        let mut seq = Self {
+            split: None,
+            width: None,
+            trailing: None,
+        };
+        if meta.input.peek(syn::token::Paren) {
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("split") {
+                    seq.split = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("width") {
+                    seq.width = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("trailing") {
+                    seq.trailing = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown"))
+                }
+            })?;
+        }
+        Ok(seq)
+    }
+
+    /// The format of the sequence, given the format of its elements.
+    fn with(self, element: syn::Type) -> syn::Result<syn::Type> {
+        let width = match self.width {
+            Some(width) => width.base10_parse::<usize>()?,
+            None => 1,
+        };
+        match (self.split, self.trailing) {
+            (Some(split), trailing) => {
+                let trailing = trailing.as_ref().map(syn::LitBool::value).unwrap_or(false);
+                Ok(syn::parse_quote!(__format::Seq<#element, #split, #width, #trailing>))
+            }
+            (None, None) => Ok(syn::parse_quote!(__format::Chars<#element, #width>)),
+            (None, Some(trailing)) => {
+                Err(syn::Error::new(trailing.span(), "trailing without split"))
+            }
+        }
+    }
+}
+
+impl Field {
+    /// The value of the field when it is missing.
+    fn default_value(&self) -> syn::Expr {
+        match &self.default {
+            Some(DefaultValue::Expr(default)) => default.clone(),
+            Some(DefaultValue::Trait) | None => {
+                let ty = &self.ty;
+                syn::parse_quote!(<#ty as __std::default::Default>::default())
+            }
+        }
+    }
+
+    /// Attributes `error` to this field, decoded from the slice `field` of `value`.
+    fn error(&self, error: syn::Expr, field: syn::Expr) -> syn::Expr {
+        let member = &self.member;
+        if self.flatten {
+            syn::parse_quote!(#error.within(value, #field))
+        } else {
+            syn::parse_quote!(#error.field(__std::stringify!(#member), value, #field))
         }
     }
 }
 
 impl Input {
     fn impl_generics(&self) -> impl Iterator<Item = syn::GenericParam> + '_ {
-        let Self::StructNamed { generics, .. } = self;
-        generics.params.iter().cloned().map(|mut param| {
+        self.generics.params.iter().cloned().map(|mut param| {
             match &mut param {
                 syn::GenericParam::Lifetime(param) => {
                     param.attrs.clear();
@@ -238,28 +876,29 @@ impl Input {
     }
 
     fn ty_generics(&self) -> impl Iterator<Item = syn::GenericArgument> + '_ {
-        let Self::StructNamed { generics, .. } = self;
-        generics.params.iter().map(|param| -> syn::GenericArgument {
-            match param {
-                syn::GenericParam::Lifetime(param) => {
-                    let lifetime = &param.lifetime;
-                    syn::parse_quote!(#lifetime)
-                }
-                syn::GenericParam::Type(param) => {
-                    let ident = &param.ident;
-                    syn::parse_quote!(#ident)
-                }
-                syn::GenericParam::Const(param) => {
-                    let ident = &param.ident;
-                    syn::parse_quote!(#ident)
+        self.generics
+            .params
+            .iter()
+            .map(|param| -> syn::GenericArgument {
+                match param {
+                    syn::GenericParam::Lifetime(param) => {
+                        let lifetime = &param.lifetime;
+                        syn::parse_quote!(#lifetime)
+                    }
+                    syn::GenericParam::Type(param) => {
+                        let ident = &param.ident;
+                        syn::parse_quote!(#ident)
+                    }
+                    syn::GenericParam::Const(param) => {
+                        let ident = &param.ident;
+                        syn::parse_quote!(#ident)
+                    }
                 }
-            }
-        })
+            })
     }
 
     fn where_predicates(&self) -> impl Iterator<Item = syn::WherePredicate> + '_ {
-        let Self::StructNamed { generics, .. } = self;
-        generics
+        self.generics
             .where_clause
             .iter()
             .flat_map(|where_clause| &where_clause.predicates)
@@ -0,0 +1,45 @@
+use cookieclicker_save::SaveRef;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+
+fn samples() -> Vec<(String, String)> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/samples");
+    let mut samples: Vec<_> = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let value = fs::read_to_string(&path).unwrap().trim().to_owned();
+            (name, value)
+        })
+        .collect();
+    samples.sort();
+    samples
+}
+
+fn bench(c: &mut Criterion) {
+    for (name, value) in samples() {
+        let mut group = c.benchmark_group(name);
+        group.bench_function("decode", |b| {
+            b.iter(|| cookieclicker_save::decode(black_box(&value)).unwrap())
+        });
+        group.bench_function("decode_lenient", |b| {
+            b.iter(|| cookieclicker_save::decode_lenient(black_box(&value)).unwrap())
+        });
+        let text = cookieclicker_save::decode_text(&value).unwrap();
+        group.bench_function("decode_ref", |b| {
+            b.iter(|| SaveRef::decode(black_box(&text)).unwrap())
+        });
+        let save = cookieclicker_save::decode(&value).unwrap();
+        group.bench_function("encode", |b| {
+            b.iter(|| cookieclicker_save::encode(black_box(&save)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -1,19 +1,106 @@
-use crate::error::Error;
-use crate::format;
-use std::fmt;
+mod catalog;
 
-pub(crate) struct Custom;
+pub use catalog::{Achievement, AchievementPool};
 
-impl format::Format<'_, Vec<bool>> for Custom {
-    #[tracing::instrument(err)]
-    fn decode(value: &str) -> Result<Vec<bool>, Error> {
-        format::chars(value).map(format::Standard::decode).collect()
+/// A view over `Save::achievements`, indexed by achievement ID.
+#[derive(Clone, Copy, Debug)]
+pub struct Achievements<'a>(&'a [bool]);
+
+impl<'a> Achievements<'a> {
+    pub fn new(value: &'a [bool]) -> Self {
+        Self(value)
+    }
+
+    pub fn is_won(&self, achievement: Achievement) -> bool {
+        self.is_won_id(achievement.id())
+    }
+
+    pub fn is_won_id(&self, id: usize) -> bool {
+        self.0.get(id).copied().unwrap_or(false)
+    }
+
+    /// Won achievements in the catalog, see also [`won_unknown`](Self::won_unknown).
+    pub fn won(&self) -> impl Iterator<Item = Achievement> + 'a {
+        let this = *self;
+        catalog().filter(move |achievement| this.is_won(*achievement))
     }
 
-    fn encode(value: &Vec<bool>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for v in value {
-            format::Standard::encode(v, f)?;
+    /// Achievements in the catalog that are not won.
+    pub fn missing(&self) -> impl Iterator<Item = Achievement> + 'a {
+        let this = *self;
+        catalog().filter(move |achievement| !this.is_won(*achievement))
+    }
+
+    /// IDs of won achievements that are not in the catalog.
+    pub fn won_unknown(&self) -> impl Iterator<Item = usize> + 'a {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(id, won)| **won && Achievement::from_id(*id).is_none())
+            .map(|(id, _)| id)
+    }
+
+    /// The number of won achievements that count toward milk,
+    /// or `None` if some won achievement is not in the catalog.
+    pub fn normal_won(&self) -> Option<usize> {
+        let mut count = 0;
+        for (id, won) in self.0.iter().enumerate() {
+            if *won && Achievement::from_id(id)?.pool() == AchievementPool::Normal {
+                count += 1;
+            }
+        }
+        Some(count)
+    }
+
+    /// Each milk rank takes 25 achievements, i.e. `1.0` is the first rank fully filled.
+    pub fn milk_progress(&self) -> Option<f64> {
+        Some(self.normal_won()? as f64 / 25.)
+    }
+}
+
+fn catalog() -> impl Iterator<Item = Achievement> {
+    <Achievement as strum::VariantArray>::VARIANTS
+        .iter()
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Achievement;
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_catalog() {
+        for value in [
+            include_str!("samples/00.txt"),
+            include_str!("samples/01.txt"),
+            include_str!("samples/02.txt"),
+        ] {
+            let save = crate::decode(value.trim()).unwrap();
+            for id in 0..save.achievements.len() {
+                assert!(Achievement::from_id(id).is_some(), "{id}");
+            }
+        }
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_milk() {
+        let save = crate::decode(include_str!("samples/02.txt").trim()).unwrap();
+        assert_eq!(save.achievements().milk_progress(), Some(0.));
+
+        let save = crate::decode(include_str!("samples/00.txt").trim()).unwrap();
+        assert_eq!(save.achievements().normal_won(), Some(123));
+        assert_eq!(save.achievements().milk_progress(), Some(123. / 25.));
+
+        let mut won = vec![false; 643];
+        for achievement in [
+            Achievement::WakeAndBake,
+            Achievement::Clickasmic,
+            Achievement::JustPlainLucky,
+        ] {
+            won[achievement.id()] = true;
         }
-        Ok(())
+        assert_eq!(super::Achievements::new(&won).normal_won(), Some(2));
     }
 }
@@ -0,0 +1,34 @@
+// Prints the `catalog!` entries of catalog.rs from the game's achievement table.
+// Run it in the console of the game once it has loaded, then paste the result.
+
+const variant = (name) => {
+  const words = name
+    .normalize("NFD")
+    .replace(/[\u0300-\u036f]/g, "")
+    .replace(/'/g, "")
+    .split(/[^A-Za-z0-9]+/)
+    .filter((word) => word);
+  const ident = words.map((word) => word[0].toUpperCase() + word.slice(1)).join("");
+  return /^[0-9]/.test(ident) ? `N${ident}` : ident;
+};
+
+const text = (html) => {
+  const element = document.createElement("div");
+  element.innerHTML = html.replace(/<q>.*?<\/q>/g, "");
+  return element.textContent.trim();
+};
+
+const pools = { normal: "Normal", shadow: "Shadow", dungeon: "Dungeon" };
+
+const seen = new Set();
+const lines = Game.AchievementsById.map((achievement) => {
+  let ident = variant(achievement.dname || achievement.name);
+  if (seen.has(ident)) {
+    ident = `${ident}${achievement.id}`;
+  }
+  seen.add(ident);
+  const name = JSON.stringify(achievement.dname || achievement.name);
+  const description = JSON.stringify(text(achievement.ddesc || achievement.desc));
+  return `    ${ident} = ${achievement.id}, ${name}, ${description}, ${pools[achievement.pool]};`;
+});
+console.log(lines.join("\n"));
@@ -0,0 +1,714 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum AchievementPool {
+    Normal,
+    Shadow,
+    Dungeon,
+}
+
+macro_rules! catalog {
+    ($($variant:ident = $id:literal, $name:literal, $description:literal, $pool:ident;)*) => {
+        // Achievements are listed by their IDs, i.e. the order in which the game declares them.
+        // The entries are generated with `catalog.js`.
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            Hash,
+            Deserialize,
+            Serialize,
+            strum::FromRepr,
+            strum::VariantArray,
+        )]
+        #[repr(usize)]
+        pub enum Achievement {
+            $($variant = $id,)*
+        }
+
+        impl Achievement {
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            pub fn description(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)*
+                }
+            }
+
+            pub fn pool(self) -> AchievementPool {
+                match self {
+                    $(Self::$variant => AchievementPool::$pool,)*
+                }
+            }
+        }
+    };
+}
+
+impl Achievement {
+    pub fn id(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_id(id: usize) -> Option<Self> {
+        Self::from_repr(id)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        <Self as strum::VariantArray>::VARIANTS
+            .iter()
+            .copied()
+            .find(|achievement| achievement.name() == name)
+    }
+}
+
+catalog! {
+    WakeAndBake = 0, "Wake and bake", "Bake 1 cookie in one ascension.", Normal;
+    MakingSomeDough = 1, "Making some dough", "Bake 1,000 cookies in one ascension.", Normal;
+    SoBakedRightNow = 2, "So baked right now", "Bake 100,000 cookies in one ascension.", Normal;
+    FledglingBakery = 3, "Fledgling bakery", "Bake 1 million cookies in one ascension.", Normal;
+    AffluentBakery = 4, "Affluent bakery", "Bake 100 million cookies in one ascension.", Normal;
+    WorldFamousBakery = 5, "World-famous bakery", "Bake 1 billion cookies in one ascension.", Normal;
+    CosmicBakery = 6, "Cosmic bakery", "Bake 100 billion cookies in one ascension.", Normal;
+    GalacticBakery = 7, "Galactic bakery", "Bake 1 trillion cookies in one ascension.", Normal;
+    UniversalBakery = 8, "Universal bakery", "Bake 100 trillion cookies in one ascension.", Normal;
+    TimelessBakery = 9, "Timeless bakery", "Bake 1 quadrillion cookies in one ascension.", Normal;
+    InfiniteBakery = 10, "Infinite bakery", "Bake 100 quadrillion cookies in one ascension.", Normal;
+    ImmortalBakery = 11, "Immortal bakery", "Bake 1 quintillion cookies in one ascension.", Normal;
+    DontStopMeNow = 12, "Don't stop me now", "Bake 100 quintillion cookies in one ascension.", Normal;
+    YouCanStopNow = 13, "You can stop now", "Bake 1 sextillion cookies in one ascension.", Normal;
+    CookiesAllTheWayDown = 14, "Cookies all the way down", "Bake 100 sextillion cookies in one ascension.", Normal;
+    Overdose = 15, "Overdose", "Bake 1 septillion cookies in one ascension.", Normal;
+    CasualBaking = 16, "Casual baking", "Bake 1 cookie per second.", Normal;
+    HardcoreBaking = 17, "Hardcore baking", "Bake 10 cookies per second.", Normal;
+    SteadyTastyStream = 18, "Steady tasty stream", "Bake 100 cookies per second.", Normal;
+    CookieMonster = 19, "Cookie monster", "Bake 1,000 cookies per second.", Normal;
+    MassProducer = 20, "Mass producer", "Bake 10,000 cookies per second.", Normal;
+    CookieVortex = 21, "Cookie vortex", "Bake 1 million cookies per second.", Normal;
+    CookiePulsar = 22, "Cookie pulsar", "Bake 10 million cookies per second.", Normal;
+    CookieQuasar = 23, "Cookie quasar", "Bake 100 million cookies per second.", Normal;
+    OhHeyYoureStillHere = 24, "Oh hey, you're still here", "Bake 1 billion cookies per second.", Normal;
+    LetsNeverBakeAgain = 25, "Let's never bake again", "Bake 10 billion cookies per second.", Normal;
+    Sacrifice = 26, "Sacrifice", "Ascend with 1 million cookies baked.", Normal;
+    Oblivion = 27, "Oblivion", "Ascend with 1 billion cookies baked.", Normal;
+    FromScratch = 28, "From scratch", "Ascend with 1 trillion cookies baked.", Normal;
+    Neverclick = 29, "Neverclick", "Make 1 million cookies by only having clicked 15 times.", Normal;
+    Clicktastic = 30, "Clicktastic", "Make 1,000 cookies from clicking.", Normal;
+    Clickathlon = 31, "Clickathlon", "Make 100,000 cookies from clicking.", Normal;
+    Clickolympics = 32, "Clickolympics", "Make 10,000,000 cookies from clicking.", Normal;
+    Clickorama = 33, "Clickorama", "Make 1,000,000,000 cookies from clicking.", Normal;
+    Click = 34, "Click", "Have 1 cursor.", Normal;
+    DoubleClick = 35, "Double-click", "Have 2 cursors.", Normal;
+    MouseWheel = 36, "Mouse wheel", "Have 50 cursors.", Normal;
+    OfMiceAndMen = 37, "Of Mice and Men", "Have 100 cursors.", Normal;
+    TheDigital = 38, "The Digital", "Have 200 cursors.", Normal;
+    JustWrong = 39, "Just wrong", "Sell a grandma.", Normal;
+    GrandmasCookies = 40, "Grandma's cookies", "Have 1 grandma.", Normal;
+    SloppyKisses = 41, "Sloppy kisses", "Have 50 grandmas.", Normal;
+    RetirementHome = 42, "Retirement home", "Have 100 grandmas.", Normal;
+    BoughtTheFarm = 43, "Bought the farm", "Have 1 farm.", Normal;
+    ReapWhatYouSow = 44, "Reap what you sow", "Have 50 farms.", Normal;
+    FarmIll = 45, "Farm ill", "Have 100 farms.", Normal;
+    ProductionChain = 46, "Production chain", "Have 1 factory.", Normal;
+    IndustrialRevolution = 47, "Industrial revolution", "Have 50 factories.", Normal;
+    GlobalWarming = 48, "Global warming", "Have 100 factories.", Normal;
+    YouKnowTheDrill = 49, "You know the drill", "Have 1 mine.", Normal;
+    ExcavationSite = 50, "Excavation site", "Have 50 mines.", Normal;
+    HollowThePlanet = 51, "Hollow the planet", "Have 100 mines.", Normal;
+    Expedition = 52, "Expedition", "Have 1 shipment.", Normal;
+    GalacticHighway = 53, "Galactic highway", "Have 50 shipments.", Normal;
+    FarFarAway = 54, "Far far away", "Have 100 shipments.", Normal;
+    Transmutation = 55, "Transmutation", "Have 1 alchemy lab.", Normal;
+    Transmogrification = 56, "Transmogrification", "Have 50 alchemy labs.", Normal;
+    GoldMember = 57, "Gold member", "Have 100 alchemy labs.", Normal;
+    AWholeNewWorld = 58, "A whole new world", "Have 1 portal.", Normal;
+    NowYoureThinking = 59, "Now you're thinking", "Have 50 portals.", Normal;
+    DimensionalShift = 60, "Dimensional shift", "Have 100 portals.", Normal;
+    TimeWarp = 61, "Time warp", "Have 1 time machine.", Normal;
+    AlternateTimeline = 62, "Alternate timeline", "Have 50 time machines.", Normal;
+    RewritingHistory = 63, "Rewriting history", "Have 100 time machines.", Normal;
+    OneWithEverything = 64, "One with everything", "Have at least 1 of every building.", Normal;
+    Mathematician = 65, "Mathematician", "Have at least 1 of the most expensive object, 2 of the second-most expensive, 4 of the next and so on (capped at 128).", Normal;
+    Base10 = 66, "Base 10", "Have at least 10 of the most expensive object, 20 of the second-most expensive, 30 of the next and so on.", Normal;
+    GoldenCookie = 67, "Golden cookie", "Click a golden cookie.", Normal;
+    LuckyCookie = 68, "Lucky cookie", "Click 7 golden cookies.", Normal;
+    AStrokeOfLuck = 69, "A stroke of luck", "Click 27 golden cookies.", Normal;
+    CheatedCookiesTasteAwful = 70, "Cheated cookies taste awful", "Hack in some cookies.", Shadow;
+    UncannyClicker = 71, "Uncanny clicker", "Click really, really fast.", Normal;
+    Builder = 72, "Builder", "Own 100 buildings.", Normal;
+    Architect = 73, "Architect", "Own 500 buildings.", Normal;
+    Enhancer = 74, "Enhancer", "Purchase 20 upgrades.", Normal;
+    Augmenter = 75, "Augmenter", "Purchase 50 upgrades.", Normal;
+    CookieDunker = 76, "Cookie-dunker", "Dunk the cookie.", Normal;
+    Fortune = 77, "Fortune", "Click 77 golden cookies.", Normal;
+    TrueNeapolitan = 78, "True Neapolitan", "Successfully complete a season without buying a single upgrade.", Shadow;
+    ElderNap = 79, "Elder nap", "Appease the grandmatriarchs at least once.", Normal;
+    ElderSlumber = 80, "Elder slumber", "Appease the grandmatriarchs at least 5 times.", Normal;
+    Elder = 81, "Elder", "Own at least 7 grandma types.", Normal;
+    ElderCalm = 82, "Elder calm", "Declare a covenant with the grandmatriarchs.", Normal;
+    Engineer = 83, "Engineer", "Own 1000 buildings.", Normal;
+    Leprechaun = 84, "Leprechaun", "Click 777 golden cookies.", Normal;
+    BlackCatsPaw = 85, "Black cat's paw", "Click 7777 golden cookies.", Normal;
+    Nihilism = 86, "Nihilism", "Ascend with 1 quadrillion cookies baked.", Normal;
+    Antibatter = 87, "Antibatter", "Have 1 antimatter condenser.", Normal;
+    QuirkyQuarks = 88, "Quirky quarks", "Have 50 antimatter condensers.", Normal;
+    ItDoesMatter = 89, "It does matter!", "Have 100 antimatter condensers.", Normal;
+    Upgrader = 90, "Upgrader", "Purchase 100 upgrades.", Normal;
+    Centennial = 91, "Centennial", "Have at least 100 of everything.", Normal;
+    Hardcore = 92, "Hardcore", "Get to 1 billion cookies baked with no upgrades purchased.", Shadow;
+    SpeedBakingI = 93, "Speed baking I", "Get to 1 million cookies baked in 35 minutes.", Shadow;
+    SpeedBakingII = 94, "Speed baking II", "Get to 1 million cookies baked in 25 minutes.", Shadow;
+    SpeedBakingIII = 95, "Speed baking III", "Get to 1 million cookies baked in 15 minutes.", Shadow;
+    GettingEvenWithTheOven = 96, "Getting even with the oven", "Defeat the Sentient Furnace in the factory dungeons.", Dungeon;
+    NowThisIsPodSmashing = 97, "Now this is pod-smashing", "Defeat the Ascended Baking Pod in the factory dungeons.", Dungeon;
+    ChirpedOut = 98, "Chirped out", "Find and defeat Chirpy, the dysfunctionning alarm bot.", Dungeon;
+    FollowTheWhiteRabbit = 99, "Follow the white rabbit", "Find and defeat the elusive sugar bunny.", Dungeon;
+    Clickasmic = 100, "Clickasmic", "Make 100,000,000,000 cookies from clicking.", Normal;
+    FriendOfTheAncients = 101, "Friend of the ancients", "Have 150 grandmas.", Normal;
+    RulerOfTheAncients = 102, "Ruler of the ancients", "Have 200 grandmas.", Normal;
+    Wholesome = 103, "Wholesome", "Unlock 100% of your heavenly chips power.", Normal;
+    JustPlainLucky = 104, "Just plain lucky", "You have 1 chance in 500,000 every second of earning this achievement.", Shadow;
+    Itchscratcher = 105, "Itchscratcher", "Burst 1 wrinkler.", Normal;
+    Wrinklesquisher = 106, "Wrinklesquisher", "Burst 50 wrinklers.", Normal;
+    Moistburster = 107, "Moistburster", "Burst 200 wrinklers.", Normal;
+    SpookyCookies = 108, "Spooky cookies", "Unlock every Halloween-themed cookie.Owning this achievement makes Halloween-themed cookies drop more frequently in future playthroughs.", Normal;
+    ComingToTown = 109, "Coming to town", "Reach Santa's 7th form.", Normal;
+    AllHailSanta = 110, "All hail Santa", "Reach Santa's final form.", Normal;
+    LetItSnow = 111, "Let it snow", "Unlock every Christmas-themed cookie.Owning this achievement makes Christmas-themed cookies drop more frequently in future playthroughs.", Normal;
+    OhDeer = 112, "Oh deer", "Pop 1 reindeer.", Normal;
+    SleighOfHand = 113, "Sleigh of hand", "Pop 50 reindeer.", Normal;
+    ReindeerSleigher = 114, "Reindeer sleigher", "Pop 200 reindeer.", Normal;
+    PerfectedAgriculture = 115, "Perfected agriculture", "Have 150 farms.", Normal;
+    UltimateAutomation = 116, "Ultimate automation", "Have 150 factories.", Normal;
+    CanYouDigIt = 117, "Can you dig it", "Have 150 mines.", Normal;
+    TypeIICivilization = 118, "Type II civilization", "Have 150 shipments.", Normal;
+    GildWars = 119, "Gild wars", "Have 150 alchemy labs.", Normal;
+    BrainSplit = 120, "Brain-split", "Have 150 portals.", Normal;
+    TimeDuke = 121, "Time duke", "Have 150 time machines.", Normal;
+    MolecularMaestro = 122, "Molecular maestro", "Have 150 antimatter condensers.", Normal;
+    LonePhoton = 123, "Lone photon", "Have 1 prism.", Normal;
+    DazzlingGlimmer = 124, "Dazzling glimmer", "Have 50 prisms.", Normal;
+    BlindingFlash = 125, "Blinding flash", "Have 100 prisms.", Normal;
+    UnendingGlow = 126, "Unending glow", "Have 150 prisms.", Normal;
+    LordOfConstructs = 127, "Lord of Constructs", "Own 2000 buildings.", Normal;
+    LordOfProgress = 128, "Lord of Progress", "Purchase 200 upgrades.", Normal;
+    Bicentennial = 129, "Bicentennial", "Have at least 200 of everything.", Normal;
+    LovelyCookies = 130, "Lovely cookies", "Unlock every Valentine-themed cookie.", Normal;
+    CentennialAndAHalf = 131, "Centennial and a half", "Have at least 150 of everything.", Normal;
+    TinyCookie = 132, "Tiny cookie", "Click the tiny cookie.", Normal;
+    YouWinACookie = 133, "You win a cookie", "This is for baking 10 trillion cookies and making it on the local news.", Normal;
+    ClickDelegator = 134, "Click delegator", "Make 1 quintillion cookies just from cursors.", Normal;
+    GushingGrannies = 135, "Gushing grannies", "Make 1 quintillion cookies just from grandmas.", Normal;
+    IHateManure = 136, "I hate manure", "Make 1 quintillion cookies just from farms.", Normal;
+    NeverDigDown = 137, "Never dig down", "Make 1 quintillion cookies just from mines.", Normal;
+    TheIncredibleMachine = 138, "The incredible machine", "Make 1 quintillion cookies just from factories.", Normal;
+    AndBeyond = 139, "And beyond", "Make 1 quintillion cookies just from shipments.", Normal;
+    MagnumOpus = 140, "Magnum Opus", "Make 1 quintillion cookies just from alchemy labs.", Normal;
+    WithStrangeEons = 141, "With strange eons", "Make 1 quintillion cookies just from portals.", Normal;
+    SpacetimeJigamaroo = 142, "Spacetime jigamaroo", "Make 1 quintillion cookies just from time machines.", Normal;
+    Supermassive = 143, "Supermassive", "Make 1 quintillion cookies just from antimatter condensers.", Normal;
+    PraiseTheSun = 144, "Praise the sun", "Make 1 quintillion cookies just from prisms.", Normal;
+    Clickageddon = 145, "Clickageddon", "Make 10,000,000,000,000 cookies from clicking.", Normal;
+    Clicknarok = 146, "Clicknarok", "Make 1,000,000,000,000,000 cookies from clicking.", Normal;
+    ExtremePolydactyly = 147, "Extreme polydactyly", "Have 300 cursors.", Normal;
+    DrT = 148, "Dr. T", "Have 400 cursors.", Normal;
+    TheOldNeverBotheredMeAnyway = 149, "The old never bothered me anyway", "Have 250 grandmas.", Normal;
+    Homegrown = 150, "Homegrown", "Have 200 farms.", Normal;
+    Technocracy = 151, "Technocracy", "Have 200 factories.", Normal;
+    TheCenterOfTheEarth = 152, "The center of the Earth", "Have 200 mines.", Normal;
+    WeComeInPeace = 153, "We come in peace", "Have 200 shipments.", Normal;
+    TheSecretsOfTheUniverse = 154, "The secrets of the universe", "Have 200 alchemy labs.", Normal;
+    RealmOfTheMadGod = 155, "Realm of the Mad God", "Have 200 portals.", Normal;
+    ForeverAndEver = 156, "Forever and ever", "Have 200 time machines.", Normal;
+    WalkThePlanck = 157, "Walk the planck", "Have 200 antimatter condensers.", Normal;
+    RiseAndShine = 158, "Rise and shine", "Have 200 prisms.", Normal;
+    GodComplex = 159, "God complex", "Name yourself Orteil.Note : usurpers incur a -1$ CpS penalty until they rename themselves something else.", Shadow;
+    ThirdParty = 160, "Third-party", "Use an add-on.", Shadow;
+    Dematerialize = 161, "Dematerialize", "Ascend with 1 quintillion cookies baked.", Normal;
+    NilZeroZilch = 162, "Nil zero zilch", "Ascend with 1 sextillion cookies baked.", Normal;
+    Transcendence = 163, "Transcendence", "Ascend with 1 septillion cookies baked.", Normal;
+    Obliterate = 164, "Obliterate", "Ascend with 1 octillion cookies baked.", Normal;
+    NegativeVoid = 165, "Negative void", "Ascend with 1 nonillion cookies baked.", Normal;
+    TheHuntIsOn = 166, "The hunt is on", "Unlock 1 egg.", Normal;
+    EggingOn = 167, "Egging on", "Unlock 7 eggs.", Normal;
+    MassEasteria = 168, "Mass Easteria", "Unlock 14 eggs.", Normal;
+    HideSeekChampion = 169, "Hide & seek champion", "Unlock all the eggs.Owning this achievement makes eggs drop more frequently in future playthroughs.", Normal;
+    WhatsInAName = 170, "What's in a name", "Give your bakery a name.", Normal;
+    PrettyPenny = 171, "Pretty penny", "Have 1 bank.", Normal;
+    FitTheBill = 172, "Fit the bill", "Have 50 banks.", Normal;
+    ALoanInTheDark = 173, "A loan in the dark", "Have 100 banks.", Normal;
+    NeedForGreed = 174, "Need for greed", "Have 150 banks.", Normal;
+    ItsTheEconomyStupid = 175, "It's the economy, stupid", "Have 200 banks.", Normal;
+    YourTimeToShrine = 176, "Your time to shrine", "Have 1 temple.", Normal;
+    ShadySect = 177, "Shady sect", "Have 50 temples.", Normal;
+    NewAgeCult = 178, "New-age cult", "Have 100 temples.", Normal;
+    OrganizedReligion = 179, "Organized religion", "Have 150 temples.", Normal;
+    Fanaticism = 180, "Fanaticism", "Have 200 temples.", Normal;
+    Bewitched = 181, "Bewitched", "Have 1 wizard tower.", Normal;
+    TheSorcerersApprentice = 182, "The sorcerer's apprentice", "Have 50 wizard towers.", Normal;
+    CharmsAndEnchantments = 183, "Charms and enchantments", "Have 100 wizard towers.", Normal;
+    CursesAndMaledictions = 184, "Curses and maledictions", "Have 150 wizard towers.", Normal;
+    MagicKingdom = 185, "Magic kingdom", "Have 200 wizard towers.", Normal;
+    VestedInterest = 186, "Vested interest", "Make 1 quintillion cookies just from banks.", Normal;
+    NewWorldOrder = 187, "New world order", "Make 1 quintillion cookies just from temples.", Normal;
+    HocusPocus = 188, "Hocus pocus", "Make 1 quintillion cookies just from wizard towers.", Normal;
+    Clickastrophe = 189, "Clickastrophe", "Make 100,000,000,000,000,000 cookies from clicking.", Normal;
+    Clickataclysm = 190, "Clickataclysm", "Make 10,000,000,000,000,000,000 cookies from clicking.", Normal;
+    ThumbsPhalangesMetacarpals = 191, "Thumbs, phalanges, metacarpals", "Have 500 cursors.", Normal;
+    Polymath = 192, "Polymath", "Own 300 upgrades and 4000 buildings.", Normal;
+    TheElderScrolls = 193, "The elder scrolls", "Own a combined 777 grandmas and cursors.", Normal;
+    ToCrumbsYouSay = 194, "To crumbs, you say?", "Ascend with 1 decillion cookies baked.", Normal;
+    How = 195, "How?", "Bake 10 septillion cookies in one ascension.", Normal;
+    TheLandOfMilkAndCookies = 196, "The land of milk and cookies", "Bake 1 octillion cookies in one ascension.", Normal;
+    HeWhoControlsTheCookiesControlsTheUniverse = 197, "He who controls the cookies controls the universe", "Bake 10 octillion cookies in one ascension.", Normal;
+    TonightOnHoarders = 198, "Tonight on Hoarders", "Bake 1 nonillion cookies in one ascension.", Normal;
+    AreYouGonnaEatAllThat = 199, "Are you gonna eat all that?", "Bake 10 nonillion cookies in one ascension.", Normal;
+    WereGonnaNeedABiggerBakery = 200, "We're gonna need a bigger bakery", "Bake 1 decillion cookies in one ascension.", Normal;
+    InTheMouthOfMadness = 201, "In the mouth of madness", "Bake 10 decillion cookies in one ascension.", Normal;
+    BroughtToYouByTheLetter = 202, "Brought to you by the letter 🍪", "Bake 1 undecillion cookies in one ascension.", Normal;
+    AWorldFilledWithCookies = 203, "A world filled with cookies", "Bake 100 billion cookies per second.", Normal;
+    WhenThisBabyHits36QuadrillionCookiesPerHour = 204, "When this baby hits 36 quadrillion cookies per hour", "Bake 1 trillion cookies per second.", Normal;
+    FastAndDelicious = 205, "Fast and delicious", "Bake 10 trillion cookies per second.", Normal;
+    CookiehertzAReallyReallyTastyHertz = 206, "Cookiehertz : a really, really tasty hertz", "Bake 100 trillion cookies per second.", Normal;
+    WoopsYouSolvedWorldHunger = 207, "Woops, you solved world hunger", "Bake 1 quadrillion cookies per second.", Normal;
+    Turbopuns = 208, "Turbopuns", "Bake 10 quadrillion cookies per second.", Normal;
+    FasterMenner = 209, "Faster menner", "Bake 100 quadrillion cookies per second.", Normal;
+    AndYetYoureStillHungry = 210, "And yet you're still hungry", "Bake 1 quintillion cookies per second.", Normal;
+    TheAgemaster = 211, "The agemaster", "Have 300 grandmas.", Normal;
+    GardenerExtraordinaire = 212, "Gardener extraordinaire", "Have 250 farms.", Normal;
+    TectonicAmbassador = 213, "Tectonic ambassador", "Have 250 mines.", Normal;
+    RiseOfTheMachines = 214, "Rise of the machines", "Have 250 factories.", Normal;
+    AcquireCurrency = 215, "Acquire currency", "Have 250 banks.", Normal;
+    Zealotry = 216, "Zealotry", "Have 250 temples.", Normal;
+    TheWizardingWorld = 217, "The wizarding world", "Have 250 wizard towers.", Normal;
+    ParsecMasher = 218, "Parsec-masher", "Have 250 shipments.", Normal;
+    TheWorkOfALifetime = 219, "The work of a lifetime", "Have 250 alchemy labs.", Normal;
+    APlaceLostInTime = 220, "A place lost in time", "Have 250 portals.", Normal;
+    HeatDeath = 221, "Heat death", "Have 250 time machines.", Normal;
+    Microcosm = 222, "Microcosm", "Have 250 antimatter condensers.", Normal;
+    BrightFuture = 223, "Bright future", "Have 250 prisms.", Normal;
+    SevenHorseshoes = 224, "Seven horseshoes", "Click 27777 golden cookies.", Normal;
+    LastChanceToSee = 225, "Last chance to see", "Burst the near-extinct shiny wrinkler.", Normal;
+    TheUltimateClickdown = 226, "The ultimate clickdown", "Make 1,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    EndlessCycle = 227, "Endless cycle", "Ascend 1000 times.", Shadow;
+    BicentennialAndAHalf = 228, "Bicentennial and a half", "Have at least 250 of everything.", Normal;
+    Tricentennial = 229, "Tricentennial", "Have at least 300 of everything.", Normal;
+    TheFullPicture = 230, "The full picture", "Purchase 300 upgrades.", Normal;
+    EarlyBird = 231, "Early bird", "Click a golden cookie less than 1 second after it spawns.", Normal;
+    FadingLuck = 232, "Fading luck", "Click a golden cookie less than 1 second before it dies.", Normal;
+    TabloidAddiction = 233, "Tabloid addiction", "Click on the news ticker 50 times.", Normal;
+    OldenDays = 234, "Olden days", "Find the forgotten madeleine.", Normal;
+    WhenTheresNothingLeftToAdd = 235, "When there's nothing left to add", "Purchase 400 upgrades.", Normal;
+    GrandDesign = 236, "Grand design", "Own 4000 buildings.", Normal;
+    Ecumenopolis = 237, "Ecumenopolis", "Own 8000 buildings.", Normal;
+    FourLeafCookie = 238, "Four-leaf cookie", "Have 4 golden cookies simultaneously.", Shadow;
+    SoMuchToDoSoMuchToSee = 239, "So much to do so much to see", "Manage a cookie legacy for at least a year.", Shadow;
+    YouGetNothing = 240, "You get nothing", "Ascend with 1 undecillion cookies baked.", Normal;
+    TricentennialAndAHalf = 241, "Tricentennial and a half", "Have at least 350 of everything.", Normal;
+    TheEndOfTheWorld = 242, "The end of the world", "Ascend with 1 duodecillion cookies baked.", Normal;
+    HereBeDragon = 243, "Here be dragon", "Hatch your dragon.", Normal;
+    FingerClickinGood = 244, "Finger clickin' good", "Make 10 sextillion cookies just from cursors.", Normal;
+    PanicAtTheBingo = 245, "Panic at the bingo", "Make 1 sextillion cookies just from grandmas.", Normal;
+    RakeInTheDough = 246, "Rake in the dough", "Make 1 sextillion cookies just from farms.", Normal;
+    QuarryOn = 247, "Quarry on", "Make 1 sextillion cookies just from mines.", Normal;
+    YesILoveTechnology = 248, "Yes I love technology", "Make 1 sextillion cookies just from factories.", Normal;
+    PaidInFull = 249, "Paid in full", "Make 1 sextillion cookies just from banks.", Normal;
+    ChurchOfCookiology = 250, "Church of Cookiology", "Make 1 sextillion cookies just from temples.", Normal;
+    TooManyRabbitsNotEnoughHats = 251, "Too many rabbits, not enough hats", "Make 1 sextillion cookies just from wizard towers.", Normal;
+    SignedSealedDelivered = 252, "Signed, sealed, delivered", "Make 1 sextillion cookies just from shipments.", Normal;
+    SugarSpiceAndEverythingNice = 253, "Sugar, spice, and everything nice", "Make 1 sextillion cookies just from alchemy labs.", Normal;
+    NotEvenRemotelyCloseToKansasAnymore = 254, "Not even remotely close to Kansas anymore", "Make 1 sextillion cookies just from portals.", Normal;
+    IOnlyMeantToStayAWhile = 255, "I only meant to stay a while", "Make 1 sextillion cookies just from time machines.", Normal;
+    Not20YearsAwayForever = 256, "Not 20 years away forever", "Make 1 sextillion cookies just from antimatter condensers.", Normal;
+    BrightSideOfTheMoon = 257, "Bright side of the moon", "Make 1 sextillion cookies just from prisms.", Normal;
+    HereYouGo = 258, "Here you go", "Click this achievement's slot.", Normal;
+    WhenTheCookiesAscendJustRight = 259, "When the cookies ascend just right", "Ascend with exactly 1 cookie.", Shadow;
+    Quadricentennial = 260, "Quadricentennial", "Have at least 400 of everything.", Normal;
+    LuckedOut = 261, "Lucked out", "Have 1 chancemaker.", Normal;
+    WhatAreTheOdds = 262, "What are the odds", "Have 50 chancemakers.", Normal;
+    GrandmaNeedsANewPairOfShoes = 263, "Grandma needs a new pair of shoes", "Have 100 chancemakers.", Normal;
+    MillionToOneShotDoc = 264, "Million to one shot, doc", "Have 150 chancemakers.", Normal;
+    AsLuckWouldHaveIt = 265, "As luck would have it", "Have 200 chancemakers.", Normal;
+    FingersCrossed = 266, "Fingers crossed", "Make 1 quintillion cookies just from chancemakers.", Normal;
+    DudeSweet = 267, "Dude, sweet", "Harvest 7 coalescing sugar lumps.", Normal;
+    SugarRush = 268, "Sugar rush", "Harvest 30 coalescing sugar lumps.", Normal;
+    YearsWorthOfCavities = 269, "Year's worth of cavities", "Harvest 365 coalescing sugar lumps.", Normal;
+    HandPicked = 270, "Hand-picked", "Successfully harvest a coalescing sugar lump before it's ripe.", Normal;
+    SugarSugar = 271, "Sugar sugar", "Harvest a bifurcated sugar lump.", Normal;
+    AllNaturalCaneSugar = 272, "All-natural cane sugar", "Harvest a golden sugar lump.", Shadow;
+    Sweetmeats = 273, "Sweetmeats", "Harvest a caramelized sugar lump.", Normal;
+    FreakyJazzHands = 274, "Freaky jazz hands", "Reach level 10 cursors.", Normal;
+    Methuselah = 275, "Methuselah", "Reach level 10 grandmas.", Normal;
+    HugeTractsOfLand = 276, "Huge tracts of land", "Reach level 10 farms.", Normal;
+    DDDDDeeper = 277, "D-d-d-d-deeper", "Reach level 10 mines.", Normal;
+    PatentlyGenius = 278, "Patently genius", "Reach level 10 factories.", Normal;
+    ACapitalIdea = 279, "A capital idea", "Reach level 10 banks.", Normal;
+    ItBelongsInAMuseum = 280, "It belongs in a museum", "Reach level 10 temples.", Normal;
+    Motormouth = 281, "Motormouth", "Reach level 10 wizard towers.", Normal;
+    BeenThereDoneThat = 282, "Been there done that", "Reach level 10 shipments.", Normal;
+    PhlogisticatedSubstances = 283, "Phlogisticated substances", "Reach level 10 alchemy labs.", Normal;
+    BizarroWorld = 284, "Bizarro world", "Reach level 10 portals.", Normal;
+    TheLongNow = 285, "The long now", "Reach level 10 time machines.", Normal;
+    ChubbyHadrons = 286, "Chubby hadrons", "Reach level 10 antimatter condensers.", Normal;
+    Palettable = 287, "Palettable", "Reach level 10 prisms.", Normal;
+    LetsLeafItToChance = 288, "Let's leaf it to chance", "Reach level 10 chancemakers.", Normal;
+    BibbidiBobbidiBoo = 289, "Bibbidi-bobbidi-boo", "Cast 9 spells.", Normal;
+    ImTheWiz = 290, "I'm the wiz", "Cast 99 spells.", Normal;
+    AWizardIsYou = 291, "A wizard is you", "Cast 999 spells.", Normal;
+    SeedlessToNay = 292, "Seedless to nay", "Convert a complete seed log into sugar lumps by sacrificing your garden to the sugar hornets.", Normal;
+    KeeperOfTheConservatory = 293, "Keeper of the conservatory", "Unlock every garden seed.", Normal;
+    InTheGardenOfEdenBaby = 294, "In the garden of Eden (baby)", "Harvest 1,000 mature garden plants.", Normal;
+    GreenAchingThumb = 295, "Green, aching thumb", "Harvest 100 mature garden plants.", Normal;
+    WithHerFingerAndHerThumb = 296, "With her finger and her thumb", "Have 600 cursors.", Normal;
+    ToOldlyGo = 297, "To oldly go", "Have 350 grandmas.", Normal;
+    SeedyBusiness = 298, "Seedy business", "Have 300 farms.", Normal;
+    FreakFracking = 299, "Freak fracking", "Have 300 mines.", Normal;
+    ModernTimes = 300, "Modern times", "Have 300 factories.", Normal;
+    TheNerveOfWar = 301, "The nerve of war", "Have 300 banks.", Normal;
+    Wololo = 302, "Wololo", "Have 300 temples.", Normal;
+    AndNowForMyNextTrickIllNeedAVolunteerFromTheAudience = 303, "And now for my next trick, I'll need a volunteer from the audience", "Have 300 wizard towers.", Normal;
+    ItsNotDelivery = 304, "It's not delivery", "Have 300 shipments.", Normal;
+    GoldJerryGold = 305, "Gold, Jerry! Gold!", "Have 300 alchemy labs.", Normal;
+    ForbiddenZone = 306, "Forbidden zone", "Have 300 portals.", Normal;
+    CookieClickerForeverAndForeverAHundredYearsCookieClickerAllDayLongForeverForeverAHundredTimesOverAndOverCookieClickerAdventuresDotCom = 307, "cookie clicker forever and forever a hundred years cookie clicker, all day long forever, forever a hundred times, over and over cookie clicker adventures dot com", "Have 300 time machines.", Normal;
+    ScientistsBaffledEverywhere = 308, "Scientists baffled everywhere", "Have 300 antimatter condensers.", Normal;
+    HarmonyOfTheSpheres = 309, "Harmony of the spheres", "Have 300 prisms.", Normal;
+    EverInYourFavor = 310, "Ever in your favor", "Have 250 chancemakers.", Normal;
+    TheAbakening = 311, "The Abakening", "Bake 10 quintillion cookies per second.", Normal;
+    TheresReallyNoHardLimitToHowLongTheseAchievementNamesCanBeAndToBeQuiteHonestImRatherCuriousToSeeHowFarWeCanGo = 312, "There's really no hard limit to how long these achievement names can be and to be quite honest I'm rather curious to see how far we can go.", "Bake 100 quintillion cookies per second.", Normal;
+    TheDreamsInWhichImBakingAreTheBestIveEverHad = 313, "The dreams in which I'm baking are the best I've ever had", "Bake 10 undecillion cookies in one ascension.", Normal;
+    SetForLife = 314, "Set for life", "Bake 1 duodecillion cookies in one ascension.", Normal;
+    AllTheOtherKidsWithThePumpedUpClicks = 315, "All the other kids with the pumped up clicks", "Make 100,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    OneMoreClick = 316, "One...more...click...", "Make 10,000,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    SelfContained = 317, "Self-contained", "Have 1 fractal engine.", Normal;
+    ThrewYouForALoop = 318, "Threw you for a loop", "Have 50 fractal engines.", Normal;
+    TheSumOfItsParts = 319, "The sum of its parts", "Have 100 fractal engines.", Normal;
+    BearsRepeating = 320, "Bears repeating", "Have 150 fractal engines.", Normal;
+    MoreOfTheSame = 321, "More of the same", "Have 200 fractal engines.", Normal;
+    LastRecurse = 322, "Last recurse", "Have 250 fractal engines.", Normal;
+    OutOfOneMany = 323, "Out of one, many", "Have 300 fractal engines.", Normal;
+    BeyondTheFractal = 324, "Beyond the fractal", "Make 1 quintillion cookies just from fractal engines.", Normal;
+    Nested = 325, "Nested", "Reach level 10 fractal engines.", Normal;
+    GottaHandItToYou = 326, "Gotta hand it to you", "Have 700 cursors.", Normal;
+    AgedWell = 327, "Aged well", "Have 400 grandmas.", Normal;
+    YouAndTheBeanstalk = 328, "You and the beanstalk", "Have 350 farms.", Normal;
+    RomancingTheStone = 329, "Romancing the stone", "Have 350 mines.", Normal;
+    ExMachina = 330, "Ex machina", "Have 350 factories.", Normal;
+    AndINeedItNow = 331, "And I need it now", "Have 350 banks.", Normal;
+    PrayOnTheWeak = 332, "Pray on the weak", "Have 350 temples.", Normal;
+    ItsAKindOfMagic = 333, "It's a kind of magic", "Have 350 wizard towers.", Normal;
+    MakeItSo = 334, "Make it so", "Have 350 shipments.", Normal;
+    AllThatGlittersIsGold = 335, "All that glitters is gold", "Have 350 alchemy labs.", Normal;
+    HeComes = 336, "He Comes", "Have 350 portals.", Normal;
+    WayBackThen = 337, "Way back then", "Have 350 time machines.", Normal;
+    ExoticMatter = 338, "Exotic matter", "Have 350 antimatter condensers.", Normal;
+    AtTheEndOfTheTunnel = 339, "At the end of the tunnel", "Have 350 prisms.", Normal;
+    BeALady = 340, "Be a lady", "Have 300 chancemakers.", Normal;
+    RenaissanceBaker = 341, "Renaissance baker", "Own 400 upgrades and 8000 buildings.", Normal;
+    QuadricentennialAndAHalf = 342, "Quadricentennial and a half", "Have at least 450 of everything.", Normal;
+    PanicAtNabisco = 343, "Panic! at Nabisco", "Bake 10 duodecillion cookies in one ascension.", Normal;
+    Fast = 344, "Fast", "Bake 1 sextillion cookies per second.", Normal;
+    F12 = 345, "F12", "Have 1 javascript console.", Normal;
+    VariableSuccess = 346, "Variable success", "Have 50 javascript consoles.", Normal;
+    NoComments = 347, "No comments", "Have 100 javascript consoles.", Normal;
+    UpToCode = 348, "Up to code", "Have 150 javascript consoles.", Normal;
+    WorksOnMyMachine = 349, "Works on my machine", "Have 200 javascript consoles.", Normal;
+    TechnicalDebt = 350, "Technical debt", "Have 250 javascript consoles.", Normal;
+    MindYourLanguage = 351, "Mind your language", "Have 300 javascript consoles.", Normal;
+    Inconsolable = 352, "Inconsolable", "Make 1 quintillion cookies just from javascript consoles.", Normal;
+    Closure = 353, "Closure", "Reach level 10 javascript consoles.", Normal;
+    TheDevilsWorkshop = 354, "The devil's workshop", "Have 800 cursors.", Normal;
+    N101stBirthday = 355, "101st birthday", "Have 450 grandmas.", Normal;
+    HarvestMoon = 356, "Harvest moon", "Have 400 farms.", Normal;
+    Mine = 357, "Mine?", "Have 400 mines.", Normal;
+    InFullGear = 358, "In full gear", "Have 400 factories.", Normal;
+    TreacleTartEconomics = 359, "Treacle tart economics", "Have 400 banks.", Normal;
+    HolyCookiesGrandma = 360, "Holy cookies, grandma!", "Have 400 temples.", Normal;
+    ThePrestige = 361, "The Prestige", "Have 400 wizard towers.", Normal;
+    ThatsJustPeanutsToSpace = 362, "That's just peanuts to space", "Have 400 shipments.", Normal;
+    WorthItsWeightInLead = 363, "Worth its weight in lead", "Have 400 alchemy labs.", Normal;
+    WhatHappensInTheVortexStaysInTheVortex = 364, "What happens in the vortex stays in the vortex", "Have 400 portals.", Normal;
+    InvitedToYesterdaysParty = 365, "Invited to yesterday's party", "Have 400 time machines.", Normal;
+    Downsizing = 366, "Downsizing", "Have 400 antimatter condensers.", Normal;
+    MyEyes = 367, "My eyes", "Have 400 prisms.", Normal;
+    DiceyBusiness = 368, "Dicey business", "Have 350 chancemakers.", Normal;
+    AnExampleOfRecursion = 369, "An example of recursion", "Have 350 fractal engines.", Normal;
+    Quincentennial = 370, "Quincentennial", "Have at least 500 of everything.", Normal;
+    Myriad = 371, "Myriad", "Own 12000 buildings.", Normal;
+    BurstingAtTheSeams = 372, "Bursting at the seams", "Bake 100 duodecillion cookies in one ascension.", Normal;
+    JustAboutFull = 373, "Just about full", "Bake 1 tredecillion cookies in one ascension.", Normal;
+    KneadForSpeed = 374, "Knead for speed", "Bake 10 sextillion cookies per second.", Normal;
+    WellTheCookiesStartComingAndTheyDontStopComing = 375, "Well the cookies start coming and they don't stop coming", "Bake 100 sextillion cookies per second.", Normal;
+    ClicketySplit = 376, "Clickety split", "Make 1,000,000,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    AintThatAClickInTheHead = 377, "Ain't that a click in the head", "Make 100,000,000,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    OhYoureBack = 378, "Oh, you're back", "Ascend with 10 duodecillion cookies baked.", Normal;
+    Lazarus = 379, "Lazarus", "Ascend with 100 duodecillion cookies baked.", Normal;
+    ClickStarringAdamSandler = 380, "Click (starring Adam Sandler)", "Make 10 octillion cookies just from cursors.", Normal;
+    Frantiquities = 381, "Frantiquities", "Make 1 octillion cookies just from grandmas.", Normal;
+    Overgrowth = 382, "Overgrowth", "Make 1 octillion cookies just from farms.", Normal;
+    Sedimentalism = 383, "Sedimentalism", "Make 1 octillion cookies just from mines.", Normal;
+    LaborOfLove = 384, "Labor of love", "Make 1 octillion cookies just from factories.", Normal;
+    ReverseFunnelSystem = 385, "Reverse funnel system", "Make 1 octillion cookies just from banks.", Normal;
+    ThusSpokeYou = 386, "Thus spoke you", "Make 1 octillion cookies just from temples.", Normal;
+    ManafestDestiny = 387, "Manafest destiny", "Make 1 octillion cookies just from wizard towers.", Normal;
+    NeitherSnowNorRainNorHeatNorGloomOfNight = 388, "Neither snow nor rain nor heat nor gloom of night", "Make 1 octillion cookies just from shipments.", Normal;
+    IveGotTheMidasTouch = 389, "I've got the Midas touch", "Make 1 octillion cookies just from alchemy labs.", Normal;
+    WhichEternalLie = 390, "Which eternal lie", "Make 1 octillion cookies just from portals.", Normal;
+    DejaVu = 391, "Déjà vu", "Make 1 octillion cookies just from time machines.", Normal;
+    PowersOfTen = 392, "Powers of Ten", "Make 1 octillion cookies just from antimatter condensers.", Normal;
+    NowTheDarkDaysAreGone = 393, "Now the dark days are gone", "Make 1 octillion cookies just from prisms.", Normal;
+    JustAStatistic = 394, "Just a statistic", "Make 1 octillion cookies just from chancemakers.", Normal;
+    PlentyOfOthersLikeIt = 395, "Plenty of others like it", "Make 1 octillion cookies just from fractal engines.", Normal;
+    BuyBuyBuy = 396, "Buy buy buy", "Bank $1 in stock market profits in one ascension.", Normal;
+    GaseousAssets = 397, "Gaseous assets", "Have your stock market profits surpass a whole year of CpS.", Normal;
+    PyramidScheme = 398, "Pyramid scheme", "Unlock the final loan type.", Normal;
+    LiquidAssets = 399, "Liquid assets", "Have your stock market profits surpass $10,000,000.", Normal;
+    AllOnDeck = 400, "All on deck", "Have 900 cursors.", Normal;
+    ButWaitTilYouGetOlder = 401, "But wait 'til you get older", "Have 500 grandmas.", Normal;
+    MakeLikeATree = 402, "Make like a tree", "Have 450 farms.", Normal;
+    CaveStory = 403, "Cave story", "Have 450 mines.", Normal;
+    InCogNeato = 404, "In-cog-neato", "Have 450 factories.", Normal;
+    StackingItUp = 405, "Stacking it up", "Have 450 banks.", Normal;
+    BartholomewOfTheCloth = 406, "Bartholomew of the cloth", "Have 450 temples.", Normal;
+    Spellbinding = 407, "Spellbinding", "Have 450 wizard towers.", Normal;
+    SpaceSpaceSpaceSpaceSpace = 408, "Space space space space space", "Have 450 shipments.", Normal;
+    DontGetUsedToYourselfYoureGonnaHaveToChange = 409, "Don't get used to yourself, you're gonna have to change", "Have 450 alchemy labs.", Normal;
+    AWholeOtherWorld = 410, "A whole other world", "Have 450 portals.", Normal;
+    OhYouAreMe = 411, "Oh, you are me", "Have 450 time machines.", Normal;
+    ParticularParticles = 412, "Particular particles", "Have 450 antimatter condensers.", Normal;
+    FlairsNoFlares = 413, "Flairs, no flares", "Have 450 prisms.", Normal;
+    Jackpot = 414, "Jackpot", "Have 400 chancemakers.", Normal;
+    ForMoreInformationOnThisAchievementPleaseReferToItsTitle = 415, "For more information on this achievement, please refer to its title", "Have 400 fractal engines.", Normal;
+    DudeWhatIfWereAllLivingInASimulationLikeWhatIfWereAllJustCodeOnAComputerSomewhere = 416, "Dude what if we're all living in a simulation like what if we're all just code on a computer somewhere", "Have 350 javascript consoles.", Normal;
+    QuincentennialAndAHalf = 417, "Quincentennial and a half", "Have at least 550 of everything.", Normal;
+    NotSoIdle = 418, "Not so idle", "Have 1 idleverse.", Normal;
+    ParallelPlay = 419, "Parallel play", "Have 50 idleverses.", Normal;
+    AlternateRealities = 420, "Alternate realities", "Have 100 idleverses.", Normal;
+    WorldsApart = 421, "Worlds apart", "Have 150 idleverses.", Normal;
+    UniversalRemote = 422, "Universal remote", "Have 200 idleverses.", Normal;
+    IdleHands = 423, "Idle hands", "Have 250 idleverses.", Normal;
+    AThousandWorlds = 424, "A thousand worlds", "Have 300 idleverses.", Normal;
+    InfiniteIdling = 425, "Infinite idling", "Make 1 quintillion cookies just from idleverses.", Normal;
+    EternalIdling = 426, "Eternal idling", "Reach level 10 idleverses.", Normal;
+    ARoundOfApplause = 427, "A round of applause", "Have 1000 cursors.", Normal;
+    DefenseOfTheAncients = 428, "Defense of the ancients", "Have 550 grandmas.", Normal;
+    SharpestToolInTheShed = 429, "Sharpest tool in the shed", "Have 500 farms.", Normal;
+    HeyNowYoureARock = 430, "Hey now, you're a rock", "Have 500 mines.", Normal;
+    ComeTogether = 431, "Come together", "Have 500 factories.", Normal;
+    TooBigToFail = 432, "Too big to fail", "Have 500 banks.", Normal;
+    DarkSideOfTheMoon = 433, "Dark side of the moon", "Have 500 temples.", Normal;
+    Abracadabra = 434, "Abracadabra", "Have 500 wizard towers.", Normal;
+    DestinationEverywhere = 435, "Destination: Everywhere", "Have 500 shipments.", Normal;
+    MidasTouch = 436, "Midas touch", "Have 500 alchemy labs.", Normal;
+    EventHorizon = 437, "Event horizon", "Have 500 portals.", Normal;
+    AStitchInTime = 438, "A stitch in time", "Have 500 time machines.", Normal;
+    BigCrunch = 439, "Big crunch", "Have 500 antimatter condensers.", Normal;
+    FullSpectrum = 440, "Full spectrum", "Have 500 prisms.", Normal;
+    JustAFleshWound = 441, "Just a flesh wound", "Have 450 chancemakers.", Normal;
+    ImSoMetaEvenThisAchievement = 442, "I'm so meta, even this achievement", "Have 450 fractal engines.", Normal;
+    TakingTheBackStreets = 443, "Taking the back streets", "Have 400 javascript consoles.", Normal;
+    Sexcentennial = 444, "Sexcentennial", "Have at least 600 of everything.", Normal;
+    Megalopolis = 445, "Megalopolis", "Own 16000 buildings.", Normal;
+    TheFinalCountdown = 446, "The final countdown", "Purchase 600 upgrades.", Normal;
+    HungryForMore = 447, "Hungry for more", "Bake 10 tredecillion cookies in one ascension.", Normal;
+    FeedMeOrteil = 448, "Feed me, Orteil", "Bake 100 tredecillion cookies in one ascension.", Normal;
+    IfItsWorthDoingItsWorthOverdoing = 449, "If it's worth doing, it's worth overdoing", "Bake 1 septillion cookies per second.", Normal;
+    TheProofOfTheCookieIsInTheBaking = 450, "The proof of the cookie is in the baking", "Bake 10 septillion cookies per second.", Normal;
+    ClickbaitSwitch = 451, "Clickbait & switch", "Make 10,000,000,000,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    SmurfAccount = 452, "Smurf account", "Ascend with 1 tredecillion cookies baked.", Normal;
+    IfAtFirstYouDontSucceed = 453, "If at first you don't succeed", "Ascend with 1 quattuordecillion cookies baked.", Normal;
+    Clickable = 454, "Clickable", "Reach level 20 cursors.", Normal;
+    GranniesGalore = 455, "Grannies galore", "Reach level 20 grandmas.", Normal;
+    CropTop = 456, "Crop top", "Reach level 20 farms.", Normal;
+    DigDeep = 457, "Dig deep", "Reach level 20 mines.", Normal;
+    AssemblyRequired = 458, "Assembly required", "Reach level 20 factories.", Normal;
+    InterestCompounded = 459, "Interest compounded", "Reach level 20 banks.", Normal;
+    PiousCookies = 460, "Pious cookies", "Reach level 20 temples.", Normal;
+    Spellbound = 461, "Spellbound", "Reach level 20 wizard towers.", Normal;
+    ExpressDelivery = 462, "Express delivery", "Reach level 20 shipments.", Normal;
+    GildedCookies = 463, "Gilded cookies", "Reach level 20 alchemy labs.", Normal;
+    Otherworldly = 464, "Otherworldly", "Reach level 20 portals.", Normal;
+    TimeWellSpent = 465, "Time well spent", "Reach level 20 time machines.", Normal;
+    MatterOfFact = 466, "Matter of fact", "Reach level 20 antimatter condensers.", Normal;
+    LightFantastic = 467, "Light fantastic", "Reach level 20 prisms.", Normal;
+    OddsAreGood = 468, "Odds are good", "Reach level 20 chancemakers.", Normal;
+    SelfSimilar = 469, "Self-similar", "Reach level 20 fractal engines.", Normal;
+    HelloWorld = 470, "Hello world", "Reach level 20 javascript consoles.", Normal;
+    HandsDown = 471, "Hands down", "Have 1100 cursors.", Normal;
+    OldSinceForever = 472, "Old since forever", "Have 600 grandmas.", Normal;
+    BumperCrop = 473, "Bumper crop", "Have 550 farms.", Normal;
+    StoneCold = 474, "Stone cold", "Have 550 mines.", Normal;
+    WellOiledMachine = 475, "Well-oiled machine", "Have 550 factories.", Normal;
+    FortKnox = 476, "Fort Knox", "Have 550 banks.", Normal;
+    TheBellsOfHeaven = 477, "The bells of heaven", "Have 550 temples.", Normal;
+    SleightOfHand = 478, "Sleight of hand", "Have 550 wizard towers.", Normal;
+    LightYearsAhead = 479, "Light-years ahead", "Have 550 shipments.", Normal;
+    PhilosophersStone = 480, "Philosopher's stone", "Have 550 alchemy labs.", Normal;
+    GatewayDrug = 481, "Gateway drug", "Have 550 portals.", Normal;
+    DejaPast = 482, "Deja past", "Have 550 time machines.", Normal;
+    QuantumLeap = 483, "Quantum leap", "Have 550 antimatter condensers.", Normal;
+    SpectralAnalysis = 484, "Spectral analysis", "Have 550 prisms.", Normal;
+    SnakeEyes = 485, "Snake eyes", "Have 500 chancemakers.", Normal;
+    NeverGetBored = 486, "Never get bored", "Have 500 fractal engines.", Normal;
+    StackTrace = 487, "Stack trace", "Have 450 javascript consoles.", Normal;
+    Multiversal = 488, "Multiversal", "Have 350 idleverses.", Normal;
+    SexcentennialAndAHalf = 489, "Sexcentennial and a half", "Have at least 650 of everything.", Normal;
+    CookieDunkerDeluxe = 490, "Cookie-dunker deluxe", "Dunk the cookie with a full milk tank.", Normal;
+    WhatsNotClicking = 491, "What's not clicking", "Click the big cookie 10,000 times in one ascension.", Normal;
+    Rebuy = 492, "Rebuy", "Sell and buy back a building 1000 times in one ascension.", Shadow;
+    Trickster = 493, "Trickster", "Trick or treat.", Normal;
+    Brainiac = 494, "Brainiac", "Have 1 cortex baker.", Normal;
+    GreyMatter = 495, "Grey matter", "Have 50 cortex bakers.", Normal;
+    MindOverMatter = 496, "Mind over matter", "Have 100 cortex bakers.", Normal;
+    ThoughtProcess = 497, "Thought process", "Have 150 cortex bakers.", Normal;
+    BrainFreeze = 498, "Brain freeze", "Have 200 cortex bakers.", Normal;
+    BigBrain = 499, "Big brain", "Have 250 cortex bakers.", Normal;
+    HiveMind = 500, "Hive mind", "Have 300 cortex bakers.", Normal;
+    FoodForThought = 501, "Food for thought", "Make 1 quintillion cookies just from cortex bakers.", Normal;
+    Brainwave = 502, "Brainwave", "Reach level 10 cortex bakers.", Normal;
+    GraspingAtStraws = 503, "Grasping at straws", "Have 1200 cursors.", Normal;
+    OldTimer = 504, "Old-timer", "Have 650 grandmas.", Normal;
+    FieldDay = 505, "Field day", "Have 600 farms.", Normal;
+    RockBottom = 506, "Rock bottom", "Have 600 mines.", Normal;
+    FactoryReset = 507, "Factory reset", "Have 600 factories.", Normal;
+    BankOnIt = 508, "Bank on it", "Have 600 banks.", Normal;
+    TempleRun = 509, "Temple run", "Have 600 temples.", Normal;
+    WandErful = 510, "Wand-erful", "Have 600 wizard towers.", Normal;
+    CargoCult = 511, "Cargo cult", "Have 600 shipments.", Normal;
+    AlchemistsDream = 512, "Alchemist's dream", "Have 600 alchemy labs.", Normal;
+    PortalToNowhere = 513, "Portal to nowhere", "Have 600 portals.", Normal;
+    TimeAfterTime = 514, "Time after time", "Have 600 time machines.", Normal;
+    AntiEverything = 515, "Anti-everything", "Have 600 antimatter condensers.", Normal;
+    Prismatic = 516, "Prismatic", "Have 600 prisms.", Normal;
+    LuckyStreak = 517, "Lucky streak", "Have 550 chancemakers.", Normal;
+    TurtlesAllTheWayDown = 518, "Turtles all the way down", "Have 550 fractal engines.", Normal;
+    PullRequest = 519, "Pull request", "Have 500 javascript consoles.", Normal;
+    InfiniteJest = 520, "Infinite jest", "Have 400 idleverses.", Normal;
+    Septcentennial = 521, "Septcentennial", "Have at least 700 of everything.", Normal;
+    Gigalopolis = 522, "Gigalopolis", "Own 20000 buildings.", Normal;
+    CookieSingularity = 523, "Cookie singularity", "Bake 1 quindecillion cookies in one ascension.", Normal;
+    CookieEventHorizon = 524, "Cookie event horizon", "Bake 100 septillion cookies per second.", Normal;
+    Clickstorm = 525, "Clickstorm", "Make 1,000,000,000,000,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    ThirdTimesTheCharm = 526, "Third time's the charm", "Ascend with 1 quindecillion cookies baked.", Normal;
+    GeniusAtWork = 527, "Genius at work", "Reach level 20 idleverses.", Normal;
+    DeepThoughts = 528, "Deep thoughts", "Reach level 20 cortex bakers.", Normal;
+    BrainFood = 529, "Brain food", "Make 1 octillion cookies just from idleverses.", Normal;
+    MeMyselfAndI = 530, "Me, myself and I", "Have 1 You.", Normal;
+    DoubleTrouble = 531, "Double trouble", "Have 50 You.", Normal;
+    Copycat = 532, "Copycat", "Have 100 You.", Normal;
+    SeeingDouble = 533, "Seeing double", "Have 150 You.", Normal;
+    AttackOfTheClones = 534, "Attack of the clones", "Have 200 You.", Normal;
+    CarbonCopy = 535, "Carbon copy", "Have 250 You.", Normal;
+    TheManyFacesOfYou = 536, "The many faces of you", "Have 300 You.", Normal;
+    SelfMade = 537, "Self-made", "Make 1 quintillion cookies just from You.", Normal;
+    SelfImprovement = 538, "Self-improvement", "Reach level 10 You.", Normal;
+    SelfActualization = 539, "Self-actualization", "Reach level 20 You.", Normal;
+    Handmade = 540, "Handmade", "Have 1300 cursors.", Normal;
+    ElderStatesgranny = 541, "Elder statesgranny", "Have 700 grandmas.", Normal;
+    GreenerPastures = 542, "Greener pastures", "Have 650 farms.", Normal;
+    GemHunter = 543, "Gem hunter", "Have 650 mines.", Normal;
+    ProductionValue = 544, "Production value", "Have 650 factories.", Normal;
+    MoneyTalks = 545, "Money talks", "Have 650 banks.", Normal;
+    FaithOfOurFathers = 546, "Faith of our fathers", "Have 650 temples.", Normal;
+    SpellItOut = 547, "Spell it out", "Have 650 wizard towers.", Normal;
+    ShipShape = 548, "Ship shape", "Have 650 shipments.", Normal;
+    AlchemistsGold = 549, "Alchemist's gold", "Have 650 alchemy labs.", Normal;
+    PortalHopping = 550, "Portal hopping", "Have 650 portals.", Normal;
+    Timeless = 551, "Timeless", "Have 650 time machines.", Normal;
+    DoesntMatter = 552, "Doesn't matter", "Have 650 antimatter condensers.", Normal;
+    PrismBreak = 553, "Prism break", "Have 650 prisms.", Normal;
+    LuckOfTheDraw = 554, "Luck of the draw", "Have 600 chancemakers.", Normal;
+    DeeperAndDeeper = 555, "Deeper and deeper", "Have 600 fractal engines.", Normal;
+    Hotfix = 556, "Hotfix", "Have 550 javascript consoles.", Normal;
+    EverythingEverywhere = 557, "Everything everywhere", "Have 450 idleverses.", Normal;
+    MindGames = 558, "Mind games", "Have 350 cortex bakers.", Normal;
+    SeptcentennialAndAHalf = 559, "Septcentennial and a half", "Have at least 750 of everything.", Normal;
+    Fractalicious = 560, "Fractalicious", "Make 1 sextillion cookies just from fractal engines.", Normal;
+    ConsoleYourself = 561, "Console yourself", "Make 1 sextillion cookies just from javascript consoles.", Normal;
+    IdleThoughts = 562, "Idle thoughts", "Make 1 sextillion cookies just from idleverses.", Normal;
+    Brainstorm = 563, "Brainstorm", "Make 1 sextillion cookies just from cortex bakers.", Normal;
+    SelfSufficient = 564, "Self-sufficient", "Make 1 sextillion cookies just from You.", Normal;
+    MassProduction = 565, "Mass production", "Make 1 octillion cookies just from cortex bakers.", Normal;
+    LivingLegend = 566, "Living legend", "Make 1 octillion cookies just from You.", Normal;
+    MouseTrap = 567, "Mouse trap", "Have 1400 cursors.", Normal;
+    GrannySmith = 568, "Granny smith", "Have 750 grandmas.", Normal;
+    FertileGround = 569, "Fertile ground", "Have 700 farms.", Normal;
+    DeepImpact = 570, "Deep impact", "Have 700 mines.", Normal;
+    CogInTheMachine = 571, "Cog in the machine", "Have 700 factories.", Normal;
+    CashCow = 572, "Cash cow", "Have 700 banks.", Normal;
+    DivineIntervention = 573, "Divine intervention", "Have 700 temples.", Normal;
+    HatTrick = 574, "Hat trick", "Have 700 wizard towers.", Normal;
+    OuterLimits = 575, "Outer limits", "Have 700 shipments.", Normal;
+    SolidGold = 576, "Solid gold", "Have 700 alchemy labs.", Normal;
+    CrossingOver = 577, "Crossing over", "Have 700 portals.", Normal;
+    BackwardsAndForwards = 578, "Backwards and forwards", "Have 700 time machines.", Normal;
+    SmashHit = 579, "Smash hit", "Have 700 antimatter condensers.", Normal;
+    Refraction = 580, "Refraction", "Have 700 prisms.", Normal;
+    RollingTheDice = 581, "Rolling the dice", "Have 650 chancemakers.", Normal;
+    SelfReference = 582, "Self-reference", "Have 650 fractal engines.", Normal;
+    LegacyCode = 583, "Legacy code", "Have 600 javascript consoles.", Normal;
+    AllOfThem = 584, "All of them", "Have 500 idleverses.", Normal;
+    BrainDrain = 585, "Brain drain", "Have 400 cortex bakers.", Normal;
+    MirrorImage = 586, "Mirror image", "Have 350 You.", Normal;
+    Octcentennial = 587, "Octcentennial", "Have at least 800 of everything.", Normal;
+    PointAndClick = 588, "Point and click", "Have 1500 cursors.", Normal;
+    GrandmotherOfAll = 589, "Grandmother of all", "Have 800 grandmas.", Normal;
+    CreamOfTheCrop = 590, "Cream of the crop", "Have 750 farms.", Normal;
+    MotherLode = 591, "Mother lode", "Have 750 mines.", Normal;
+    AssemblyLine = 592, "Assembly line", "Have 750 factories.", Normal;
+    CookieVault = 593, "Cookie vault", "Have 750 banks.", Normal;
+    SacredGrounds = 594, "Sacred grounds", "Have 750 temples.", Normal;
+    MasterOfTheArcane = 595, "Master of the arcane", "Have 750 wizard towers.", Normal;
+    Frontier = 596, "Frontier", "Have 750 shipments.", Normal;
+    TransmutationStation = 597, "Transmutation station", "Have 750 alchemy labs.", Normal;
+    OtherSide = 598, "Other side", "Have 750 portals.", Normal;
+    EndOfTime = 599, "End of time", "Have 750 time machines.", Normal;
+    AtomSmasher = 600, "Atom smasher", "Have 750 antimatter condensers.", Normal;
+    Spectrum = 601, "Spectrum", "Have 750 prisms.", Normal;
+    HouseEdge = 602, "House edge", "Have 700 chancemakers.", Normal;
+    InfiniteRegress = 603, "Infinite regress", "Have 700 fractal engines.", Normal;
+    ShipIt = 604, "Ship it", "Have 650 javascript consoles.", Normal;
+    Multitude = 605, "Multitude", "Have 550 idleverses.", Normal;
+    ThinkTank = 606, "Think tank", "Have 450 cortex bakers.", Normal;
+    CrowdOfOne = 607, "Crowd of one", "Have 400 You.", Normal;
+    OctcentennialAndAHalf = 608, "Octcentennial and a half", "Have at least 850 of everything.", Normal;
+    PlanetCookie = 609, "Planet cookie", "Own 25000 buildings.", Normal;
+    TheFullCollection = 610, "The full collection", "Purchase 700 upgrades.", Normal;
+    ACookieForEveryAtom = 611, "A cookie for every atom", "Bake 10 quindecillion cookies in one ascension.", Normal;
+    AndThenWhat = 612, "And then what?", "Bake 100 quindecillion cookies in one ascension.", Normal;
+    CookieSupernova = 613, "Cookie supernova", "Bake 1 octillion cookies per second.", Normal;
+    CookieHypernova = 614, "Cookie hypernova", "Bake 10 octillion cookies per second.", Normal;
+    ClickOfTheTitans = 615, "Click of the titans", "Make 100,000,000,000,000,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    Clickpocalypse = 616, "Clickpocalypse", "Make 10,000,000,000,000,000,000,000,000,000,000,000,000 cookies from clicking.", Normal;
+    StartingOver = 617, "Starting over", "Ascend with 1 sexdecillion cookies baked.", Normal;
+    OnceMoreWithFeeling = 618, "Once more, with feeling", "Ascend with 1 septendecillion cookies baked.", Normal;
+    MidasFingers = 619, "Midas fingers", "Click 83331 golden cookies.", Normal;
+    GoldenAge = 620, "Golden age", "Click 77777 golden cookies.", Normal;
+    WrinklerWhisperer = 621, "Wrinkler whisperer", "Burst 1,000 wrinklers.", Normal;
+    ElderFarewell = 622, "Elder farewell", "Burst 5,000 wrinklers.", Normal;
+    PantheonPilgrim = 623, "Pantheon pilgrim", "Slot a spirit in every slot of the pantheon.", Normal;
+    Devoted = 624, "Devoted", "Keep the same spirits slotted for a full day.", Normal;
+    Interfaith = 625, "Interfaith", "Swap spirits 100 times.", Normal;
+    GreenCookiesSleepFuriously = 626, "Green cookies sleep furiously", "Harvest a juicy queenbeet.", Normal;
+    WeedOut = 627, "Weed out", "Harvest 10,000 mature garden plants.", Normal;
+    Spellcaster = 628, "Spellcaster", "Cast 9,999 spells.", Normal;
+    BullMarket = 629, "Bull market", "Have 1,000 stocks of a single good.", Normal;
+    InsiderTrading = 630, "Insider trading", "Sell a stock at 10 times its buying price.", Normal;
+    DragonsHoard = 631, "Dragon's hoard", "Feed your dragon 1,000 buildings.", Normal;
+    Dragonflight = 632, "Dragonflight", "Train your dragon to wield two auras.", Normal;
+    SugarHigh = 633, "Sugar high", "Harvest 1,000 coalescing sugar lumps.", Normal;
+    Lumpy = 634, "Lumpy", "Have 100 sugar lumps at once.", Normal;
+    SeasonsGreetings = 635, "Season's greetings", "Unlock every seasonal cookie.", Normal;
+    Eggstraordinary = 636, "Eggstraordinary", "Hold all the eggs in a single ascension.", Normal;
+    JustTheOne = 637, "Just the one", "Ascend with a single building owned.", Shadow;
+    PatienceIsAVirtue = 638, "Patience is a virtue", "Bake 1 trillion cookies without clicking the big cookie.", Shadow;
+    CookieHoarder = 639, "Cookie hoarder", "Go a year without spending a single cookie.", Shadow;
+    Untouchable = 640, "Untouchable", "Reach 1 quadrillion cookies baked without buying an upgrade.", Shadow;
+    Nonacentennial = 641, "Nonacentennial", "Have at least 900 of everything.", Normal;
+    NothingLeftToBuy = 642, "Nothing left to buy", "Purchase 800 upgrades.", Normal;
+}
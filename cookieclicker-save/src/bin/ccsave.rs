@@ -0,0 +1,250 @@
+//! Requires the `cli` feature:
+//! `cargo install --path cookieclicker-save --features cli`.
+
+use anyhow::Context as _;
+use clap::{Parser, Subcommand};
+use cookieclicker_save::{Operation, Save, Severity};
+use serde::Serialize;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Inspect and edit Cookie Clicker save codes.
+///
+/// Inputs are read from FILE, or from stdin if it is omitted or `-`.
+#[derive(Parser)]
+struct Args {
+    /// Print JSON on a single line.
+    #[clap(long, global = true)]
+    compact: bool,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Decode a save code into JSON.
+    Decode { file: Option<PathBuf> },
+    /// Encode JSON into a save code.
+    Encode { file: Option<PathBuf> },
+    /// Print the value at PATH, such as `miscellaneous_game_data.cookies_in_bank`.
+    Get { path: String, file: Option<PathBuf> },
+    /// Set the value at PATH and print the new save code.
+    Set {
+        path: String,
+        value: String,
+        file: Option<PathBuf>,
+        /// Take VALUE as a string as is.
+        #[clap(long, conflicts_with = "json")]
+        string: bool,
+        /// Parse VALUE as JSON, the default.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Print the changes from save A to save B.
+    Diff { a: PathBuf, b: PathBuf },
+    /// Check the save for inconsistencies, failing if there are any errors.
+    Validate { file: Option<PathBuf> },
+    /// Print an overview of the bakery.
+    Summary { file: Option<PathBuf> },
+}
+
+fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt().with_writer(io::stderr).init();
+
+    run(Args::parse(), &mut io::stdin(), &mut io::stdout())
+}
+
+/// Runs the command of `args`, reading from `stdin` when no FILE is given.
+fn run(args: Args, stdin: &mut impl Read, out: &mut impl Write) -> anyhow::Result<()> {
+    let compact = args.compact;
+    match args.command {
+        Command::Decode { file } => print(out, &decode(stdin, file.as_deref())?, compact)?,
+        Command::Encode { file } => {
+            let value: Save = serde_json::from_str(&read(stdin, file.as_deref())?)?;
+            writeln!(out, "{}", cookieclicker_save::encode(&value))?;
+        }
+        Command::Get { path, file } => {
+            print(out, &decode(stdin, file.as_deref())?.get(&path)?, compact)?
+        }
+        Command::Set {
+            path,
+            value,
+            file,
+            string,
+            json: _,
+        } => {
+            let value = if string {
+                value.into()
+            } else {
+                serde_json::from_str(&value)
+                    .context("VALUE is not JSON, pass --string to set it as a string")?
+            };
+            let code = cookieclicker_save::patch(
+                &read(stdin, file.as_deref())?,
+                &[Operation { path, value }],
+            )?;
+            writeln!(out, "{code}")?;
+        }
+        Command::Diff { a, b } => {
+            let (a, b) = (decode(stdin, Some(&a))?, decode(stdin, Some(&b))?);
+            print(out, &cookieclicker_save::diff(&a, &b), compact)?;
+        }
+        Command::Validate { file } => {
+            let violations = decode(stdin, file.as_deref())?.validate();
+            for violation in &violations {
+                writeln!(out, "{:?}: {violation}", violation.severity())?;
+            }
+            let errors = violations
+                .iter()
+                .filter(|v| v.severity() == Severity::Error)
+                .count();
+            anyhow::ensure!(errors == 0, "{errors} errors");
+        }
+        Command::Summary { file } => summary(out, &decode(stdin, file.as_deref())?)?,
+    }
+    Ok(())
+}
+
+fn print(out: &mut impl Write, value: &impl Serialize, compact: bool) -> anyhow::Result<()> {
+    if compact {
+        writeln!(out, "{}", serde_json::to_string(value)?)?;
+    } else {
+        writeln!(out, "{}", serde_json::to_string_pretty(value)?)?;
+    }
+    Ok(())
+}
+
+fn read(stdin: &mut impl Read, file: Option<&Path>) -> anyhow::Result<String> {
+    let mut value = String::new();
+    match file {
+        Some(file) if file != Path::new("-") => value = std::fs::read_to_string(file)?,
+        _ => {
+            stdin.read_to_string(&mut value)?;
+        }
+    }
+    Ok(value.trim().to_owned())
+}
+
+fn decode(stdin: &mut impl Read, file: Option<&Path>) -> anyhow::Result<Save> {
+    Ok(cookieclicker_save::decode(&read(stdin, file)?)?)
+}
+
+fn summary(out: &mut impl Write, save: &Save) -> io::Result<()> {
+    let misc = &save.miscellaneous_game_data;
+
+    writeln!(
+        out,
+        "{} (version {})",
+        save.run_details.bakery_name, save.game_version,
+    )?;
+    writeln!(out, "Cookies in bank:  {}", number(misc.cookies_in_bank))?;
+    writeln!(out, "Baked this run:   {}", number(misc.cookies_baked))?;
+    writeln!(
+        out,
+        "Baked all time:   {}",
+        number(misc.cookies_baked + misc.cookies_forfeited_by_ascending),
+    )?;
+    writeln!(
+        out,
+        "Prestige:         {} ({} heavenly chips, {} ascensions)",
+        number(misc.prestive_level),
+        number(misc.heavenly_chips),
+        misc.ascensions,
+    )?;
+    writeln!(
+        out,
+        "Achievements:     {} / {} (milk {})",
+        save.achievements.iter().filter(|v| **v).count(),
+        save.achievements.len(),
+        match save.achievements().milk_progress() {
+            Some(milk) => format!("{:.0}%", milk * 100.),
+            None => "unknown".to_owned(),
+        },
+    )?;
+    writeln!(
+        out,
+        "Upgrades:         {} / {} bought",
+        save.upgrades.iter().filter(|v| v.bought).count(),
+        save.upgrades.len(),
+    )?;
+    writeln!(out, "Buildings:")?;
+    for (building, entry) in save.building_data.entries() {
+        if entry.amount_owned > 0 {
+            writeln!(
+                out,
+                "  {:<22}{:>6}  (level {})",
+                building.name(),
+                entry.amount_owned,
+                entry.level,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Formats large amounts the way the game does with short numbers off, e.g. `1.234 billion`.
+fn number(value: f64) -> String {
+    const NAMES: [&str; 10] = [
+        "million",
+        "billion",
+        "trillion",
+        "quadrillion",
+        "quintillion",
+        "sextillion",
+        "septillion",
+        "octillion",
+        "nonillion",
+        "decillion",
+    ];
+    if !value.is_finite() || value.abs() < 1e6 {
+        return format!("{}", value.round());
+    }
+    // `NAMES[0]` is 10^6, that is, 1000^2.
+    let exponent = (value.abs().log10() / 3.).floor() as usize;
+    match NAMES.get(exponent - 2) {
+        Some(name) => format!("{:.3} {name}", value / 1e3f64.powi(exponent as i32)),
+        None => format!("{value:.3e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Args;
+    use clap::Parser;
+
+    fn run(args: &[&str], stdin: &str) -> anyhow::Result<String> {
+        let args = Args::try_parse_from([&["ccsave"], args].concat())?;
+        let mut out = Vec::new();
+        super::run(args, &mut stdin.as_bytes(), &mut out)?;
+        Ok(String::from_utf8(out)?.trim().to_owned())
+    }
+
+    #[test]
+    fn test_decode_encode() {
+        let mut save =
+            cookieclicker_save::decode(include_str!("../samples/00.txt").trim()).unwrap();
+        save.miscellaneous_game_data.cookies_in_bank = f64::NAN;
+        save.miscellaneous_game_data.cookies_baked = f64::INFINITY;
+        let code = cookieclicker_save::encode(&save);
+
+        let json = run(&["decode", "--compact"], &code).unwrap();
+        assert!(json.contains(r#""cookies_in_bank":"NaN""#));
+        assert_eq!(run(&["encode"], &json).unwrap(), code);
+    }
+
+    #[test]
+    fn test_set() {
+        let code = include_str!("../samples/00.txt").trim();
+        let path = "run_details.bakery_name";
+
+        let patched = run(&["set", "--string", path, "12"], code).unwrap();
+        assert_eq!(run(&["get", path], &patched).unwrap(), r#""12""#);
+        let patched = run(&["set", path, r#""12""#], code).unwrap();
+        assert_eq!(run(&["get", path], &patched).unwrap(), r#""12""#);
+
+        // Without --string, 12 is a number, and a bare word is not JSON.
+        assert!(run(&["set", path, "12"], code).is_err());
+        assert!(run(&["set", path, "Test"], code).is_err());
+        assert!(run(&["set", "--string", "--json", path, "12"], code).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Deserialize,
+    Serialize,
+    strum::FromRepr,
+    strum::VariantArray,
+)]
+#[repr(usize)]
+pub enum Building {
+    Cursor,
+    Grandma,
+    Farm,
+    Mine,
+    Factory,
+    Bank,
+    Temple,
+    WizardTower,
+    Shipment,
+    AlchemyLab,
+    Portal,
+    TimeMachine,
+    AntimatterCondenser,
+    Prism,
+    Chancemaker,
+    FractalEngine,
+    JavascriptConsole,
+    Idleverse,
+    CortexBaker,
+    You,
+}
+
+impl Building {
+    pub fn id(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_id(id: usize) -> Option<Self> {
+        Self::from_repr(id)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cursor => "Cursor",
+            Self::Grandma => "Grandma",
+            Self::Farm => "Farm",
+            Self::Mine => "Mine",
+            Self::Factory => "Factory",
+            Self::Bank => "Bank",
+            Self::Temple => "Temple",
+            Self::WizardTower => "Wizard tower",
+            Self::Shipment => "Shipment",
+            Self::AlchemyLab => "Alchemy lab",
+            Self::Portal => "Portal",
+            Self::TimeMachine => "Time machine",
+            Self::AntimatterCondenser => "Antimatter condenser",
+            Self::Prism => "Prism",
+            Self::Chancemaker => "Chancemaker",
+            Self::FractalEngine => "Fractal engine",
+            Self::JavascriptConsole => "Javascript console",
+            Self::Idleverse => "Idleverse",
+            Self::CortexBaker => "Cortex baker",
+            Self::You => "You",
+        }
+    }
+
+    pub fn base_price(self) -> f64 {
+        match self {
+            Self::Cursor => 15.,
+            Self::Grandma => 100.,
+            Self::Farm => 1.1e3,
+            Self::Mine => 1.2e4,
+            Self::Factory => 1.3e5,
+            Self::Bank => 1.4e6,
+            Self::Temple => 2e7,
+            Self::WizardTower => 3.3e8,
+            Self::Shipment => 5.1e9,
+            Self::AlchemyLab => 7.5e10,
+            Self::Portal => 1e12,
+            Self::TimeMachine => 1.4e13,
+            Self::AntimatterCondenser => 1.7e14,
+            Self::Prism => 2.1e15,
+            Self::Chancemaker => 2.6e16,
+            Self::FractalEngine => 3.1e17,
+            Self::JavascriptConsole => 7.1e19,
+            Self::Idleverse => 1.2e22,
+            Self::CortexBaker => 1.9e24,
+            Self::You => 5.4e26,
+        }
+    }
+}
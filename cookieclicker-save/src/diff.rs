@@ -0,0 +1,265 @@
+use crate::format::{self, Format as _};
+use crate::{Achievement, Building, FarmGridData, Garden, Save, UpgradeId};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum Change {
+    /// Any other field, by its path in the serialized save, such as
+    /// `miscellaneous_game_data.cookies_in_bank` or `building_data.banks.level`.
+    Field {
+        path: String,
+        old: Value,
+        new: Value,
+    },
+    AchievementWon {
+        id: usize,
+        achievement: Option<Achievement>,
+    },
+    AchievementLost {
+        id: usize,
+        achievement: Option<Achievement>,
+    },
+    UpgradeBought {
+        id: usize,
+        upgrade: Option<UpgradeId>,
+    },
+    UpgradeLost {
+        id: usize,
+        upgrade: Option<UpgradeId>,
+    },
+    BuildingBought {
+        building: Building,
+        old: u64,
+        new: u64,
+    },
+    BuildingSold {
+        building: Building,
+        old: u64,
+        new: u64,
+    },
+    GardenTile {
+        x: usize,
+        y: usize,
+        old: Option<FarmGridData>,
+        new: Option<FarmGridData>,
+    },
+}
+
+/// Compares two saves, typically two backups of the same bakery.
+pub fn diff(old: &Save, new: &Save) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    let old_value = serde_json::to_value(old).expect("a save has only string-keyed maps");
+    let new_value = serde_json::to_value(new).expect("a save has only string-keyed maps");
+    fields(&mut String::new(), &old_value, &new_value, &mut changes);
+
+    for id in 0..old.achievements.len().max(new.achievements.len()) {
+        let won = |save: &Save| save.achievements.get(id).copied().unwrap_or(false);
+        let achievement = Achievement::from_id(id);
+        match (won(old), won(new)) {
+            (false, true) => changes.push(Change::AchievementWon { id, achievement }),
+            (true, false) => changes.push(Change::AchievementLost { id, achievement }),
+            _ => {}
+        }
+    }
+
+    for id in 0..old.upgrades.len().max(new.upgrades.len()) {
+        let bought = |save: &Save| save.upgrades.get(id).is_some_and(|v| v.bought);
+        let upgrade = UpgradeId::from_id(id);
+        match (bought(old), bought(new)) {
+            (false, true) => changes.push(Change::UpgradeBought { id, upgrade }),
+            (true, false) => changes.push(Change::UpgradeLost { id, upgrade }),
+            _ => {}
+        }
+    }
+
+    for ((building, old), (_, new)) in old
+        .building_data
+        .entries()
+        .into_iter()
+        .zip(new.building_data.entries())
+    {
+        let (old, new) = (old.amount_owned, new.amount_owned);
+        if new > old {
+            changes.push(Change::BuildingBought { building, old, new });
+        } else if new < old {
+            changes.push(Change::BuildingSold { building, old, new });
+        }
+    }
+
+    let tiles = |save: &Save| {
+        save.building_data
+            .farms
+            .minigame_data
+            .as_ref()
+            .map(|garden| garden.farm_grid_data.clone())
+            .unwrap_or_default()
+    };
+    let (old_tiles, new_tiles) = (tiles(old), tiles(new));
+    for i in 0..old_tiles.len().max(new_tiles.len()) {
+        let old = old_tiles.get(i).cloned().flatten();
+        let new = new_tiles.get(i).cloned().flatten();
+        if old != new {
+            changes.push(Change::GardenTile {
+                x: i % Garden::WIDTH,
+                y: i / Garden::WIDTH,
+                old,
+                new,
+            });
+        }
+    }
+
+    changes
+}
+
+/// Whether `path` is reported by one of the dedicated changes instead, or is the layout
+/// bookkeeping in an [`Extra`](crate::Extra), which is not part of the game state.
+fn is_dedicated(path: &str) -> bool {
+    path == "achievements"
+        || path == "extra"
+        || path.ends_with(".extra")
+        || path == "building_data.farms.minigame_data.farm_grid_data"
+        || (path.starts_with("upgrades[") && path.ends_with("].bought"))
+        || (path.starts_with("building_data.") && path.ends_with(".amount_owned"))
+}
+
+fn fields(path: &mut String, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    if is_dedicated(path) {
+        return;
+    }
+    let len = path.len();
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys = old
+                .keys()
+                .chain(new.keys().filter(|k| !old.contains_key(*k)));
+            for key in keys {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+                let old = old.get(key).unwrap_or(&Value::Null);
+                let new = new.get(key).unwrap_or(&Value::Null);
+                fields(path, old, new, changes);
+                path.truncate(len);
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for i in 0..old.len().max(new.len()) {
+                path.push_str(&format!("[{i}]"));
+                let old = old.get(i).unwrap_or(&Value::Null);
+                let new = new.get(i).unwrap_or(&Value::Null);
+                fields(path, old, new, changes);
+                path.truncate(len);
+            }
+        }
+        _ if !same(old, new) => changes.push(Change::Field {
+            path: path.clone(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Whether two leaves are equal, comparing floats on their text in a save.
+fn same(old: &Value, new: &Value) -> bool {
+    match (old, new) {
+        (Value::Number(old), Value::Number(new)) if old.is_f64() || new.is_f64() => {
+            let text = |value: &serde_json::Number| {
+                format::Standard::display(&value.as_f64().unwrap_or(f64::NAN)).to_string()
+            };
+            text(old) == text(new)
+        }
+        _ => old == new,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Change;
+    use crate::{Achievement, Building, FarmGridData, UpgradeId};
+    use serde_json::json;
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_diff() {
+        let old = crate::decode(include_str!("samples/00.txt").trim()).unwrap();
+        assert_eq!(super::diff(&old, &old), []);
+
+        assert!(old.achievements[0] && old.upgrades[1].bought);
+        let mut new = old.clone();
+        new.miscellaneous_game_data.cookie_clicks += 1;
+        new.achievements[0] = false;
+        new.upgrades[1].bought = false;
+        new.building_data.cursors.amount_owned += 2;
+        let garden = new.building_data.farms.minigame_data.as_mut().unwrap();
+        garden.farm_grid_data[7] = Some(FarmGridData { id: 1, age: 3 });
+
+        let changes = super::diff(&old, &new);
+        assert_eq!(
+            changes[0],
+            Change::Field {
+                path: "miscellaneous_game_data.cookie_clicks".into(),
+                old: json!(old.miscellaneous_game_data.cookie_clicks),
+                new: json!(new.miscellaneous_game_data.cookie_clicks),
+            },
+        );
+        assert_eq!(
+            changes[1..],
+            [
+                Change::AchievementLost {
+                    id: 0,
+                    achievement: Some(Achievement::WakeAndBake),
+                },
+                Change::UpgradeLost {
+                    id: 1,
+                    upgrade: Some(UpgradeId::CarpalTunnelPreventionCream),
+                },
+                Change::BuildingBought {
+                    building: Building::Cursor,
+                    old: old.building_data.cursors.amount_owned,
+                    new: old.building_data.cursors.amount_owned + 2,
+                },
+                Change::GardenTile {
+                    x: 1,
+                    y: 1,
+                    old: old
+                        .building_data
+                        .farms
+                        .minigame_data
+                        .as_ref()
+                        .unwrap()
+                        .farm_grid_data[7]
+                        .clone(),
+                    new: Some(FarmGridData { id: 1, age: 3 }),
+                },
+            ],
+        );
+
+        let mut old = old;
+        old.miscellaneous_game_data.cookies_in_bank = f64::NAN;
+        old.miscellaneous_game_data.cookies_baked = 0.;
+        let mut new = old.clone();
+        new.miscellaneous_game_data.cookies_in_bank = f64::INFINITY;
+        new.miscellaneous_game_data.cookies_baked = -0.;
+        new.game_version = "2.031".parse().unwrap();
+        new.run_details.extra.omitted.push("you_appearance".into());
+        assert_eq!(
+            super::diff(&old, &new),
+            [
+                Change::Field {
+                    path: "game_version".into(),
+                    old: json!("2.052"),
+                    new: json!("2.031"),
+                },
+                Change::Field {
+                    path: "miscellaneous_game_data.cookies_in_bank".into(),
+                    old: json!("NaN"),
+                    new: json!("Infinity"),
+                },
+            ],
+        );
+    }
+}
@@ -9,8 +9,134 @@ pub enum Error {
     #[error(transparent)]
     Utf8(#[from] std::string::FromUtf8Error),
 
+    /// A save code that failed to decode, after the normalization `step`.
+    #[error("{step}: {source}")]
+    Code {
+        step: &'static str,
+        source: Box<Error>,
+    },
+
     #[error("insufficient data")]
     InsufficientData,
+    /// Data after the last field of a record that does not keep unknown data.
+    #[error("trailing data")]
+    TrailingData,
     #[error("invalid data")]
     InvalidData,
+
+    #[error("{path}: no such field")]
+    InvalidPath { path: String },
+    #[error("{path}: expected {expected}, found {found}")]
+    TypeMismatch {
+        path: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+    #[error("{path}: {source}")]
+    InvalidValue {
+        path: String,
+        source: serde_json::Error,
+    },
+
+    /// An error while decoding the field at `path`, such as
+    /// `building_data.farms.minigame_data.farm_grid_data[12]`.
+    /// `offset` is the byte offset of `raw` in the decoded save string.
+    #[error("{path}: {source} at byte {offset} ({raw:?})")]
+    Field {
+        path: String,
+        raw: String,
+        offset: usize,
+        source: Box<Error>,
+    },
+}
+
+/// A field that failed to decode and was replaced, see [`decode_lenient`](crate::decode_lenient).
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub path: String,
+    pub raw: String,
+    /// The byte offset of `raw` in the decoded save string.
+    pub offset: usize,
+    pub reason: Error,
+}
+
+impl From<Error> for Diagnostic {
+    fn from(value: Error) -> Self {
+        match value {
+            Error::Field {
+                path,
+                raw,
+                offset,
+                source,
+            } => Self {
+                path,
+                raw,
+                offset,
+                reason: *source,
+            },
+            reason => Self {
+                path: String::new(),
+                raw: String::new(),
+                offset: 0,
+                reason,
+            },
+        }
+    }
+}
+
+impl Error {
+    /// The underlying error, without the field context.
+    pub fn kind(&self) -> &Self {
+        match self {
+            Self::Field { source, .. } => source.kind(),
+            _ => self,
+        }
+    }
+
+    /// Attributes the error to the field `name`, decoded from `value`, a slice of `parent`.
+    pub fn field(self, name: &str, parent: &str, value: &str) -> Self {
+        self.context(Some(name), parent, value)
+    }
+
+    /// Attributes the error to the element `index`, decoded from `value`, a slice of `parent`.
+    pub fn index(self, index: usize, parent: &str, value: &str) -> Self {
+        self.context(Some(&format!("[{index}]")), parent, value)
+    }
+
+    /// Like `field`, but without adding a path segment.
+    pub fn within(self, parent: &str, value: &str) -> Self {
+        self.context(None, parent, value)
+    }
+
+    fn context(self, segment: Option<&str>, parent: &str, value: &str) -> Self {
+        let start = parent.as_ptr() as usize;
+        let offset = (value.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= parent.len())
+            .unwrap_or(0);
+        match self {
+            Self::Field {
+                path,
+                raw,
+                offset: inner,
+                source,
+            } => Self::Field {
+                path: match segment {
+                    Some(segment) if path.is_empty() => segment.to_owned(),
+                    Some(segment) if path.starts_with('[') => format!("{segment}{path}"),
+                    Some(segment) => format!("{segment}.{path}"),
+                    None => path,
+                },
+                raw,
+                offset: offset + inner,
+                source,
+            },
+            source => Self::Field {
+                path: segment.unwrap_or_default().to_owned(),
+                raw: value.to_owned(),
+                offset,
+                source: Box::new(source),
+            },
+        }
+    }
 }
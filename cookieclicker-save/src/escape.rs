@@ -1,23 +1,122 @@
 use crate::error::Error;
-use base64::prelude::{Engine, BASE64_STANDARD};
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::prelude::*;
+use std::borrow::Cow;
+
+const END: &str = "!END!";
+
+/// The form of a save code, as detected by [`decode_with_form`](crate::decode_with_form).
+/// The default is the form the game exports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Form {
+    /// How many times the code is URL-encoded.
+    pub url_encoded: usize,
+    /// Whether the base64 uses the URL-safe alphabet, `-` and `_` instead of `+` and `/`.
+    pub url_safe: bool,
+    /// Whether the base64 is padded with `=`.
+    pub padded: bool,
+    /// Whether the base64 is followed by `!END!`.
+    pub end: bool,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self {
+            url_encoded: 1,
+            url_safe: false,
+            padded: true,
+            end: true,
+        }
+    }
+}
 
-#[tracing::instrument(err, ret(level = tracing::Level::DEBUG))]
 pub(crate) fn decode(value: &str) -> Result<String, Error> {
-    let value = urlencoding::decode(value)?;
-    let value = value.trim_end_matches("!END!");
-    let value = BASE64_STANDARD.decode(value)?;
-    Ok(String::from_utf8(value)?)
+    decode_with_form(value).map(|(value, _)| value)
+}
+
+/// Decodes the save code `value`, tolerating whitespace, URL-safe base64, missing padding
+/// and repeated URL encoding.
+pub(crate) fn decode_with_form(value: &str) -> Result<(String, Form), Error> {
+    let mut value = strip_whitespace(value.into());
+    let mut url_encoded = 0;
+    while value.contains('%') {
+        let decoded = urlencoding::decode(&value).map_err(|error| Error::Code {
+            step: "URL decoding",
+            source: Box::new(error.into()),
+        })?;
+        if decoded == value {
+            break;
+        }
+        value = strip_whitespace(decoded.into_owned().into());
+        url_encoded += 1;
+    }
+
+    let end = value.ends_with(END);
+    let value = value.strip_suffix(END).unwrap_or(&value);
+    let url_safe = value.contains(['-', '_']);
+    let padded = value.ends_with('=') || value.len().is_multiple_of(4);
+    let engine = if url_safe { &URL_SAFE } else { &STANDARD };
+    let value = engine.decode(value).map_err(|error| Error::Code {
+        step: "base64 decoding",
+        source: Box::new(error.into()),
+    })?;
+    let value = String::from_utf8(value).map_err(|error| Error::Code {
+        step: "UTF-8 decoding",
+        source: Box::new(error.into()),
+    })?;
+    let form = Form {
+        url_encoded,
+        url_safe,
+        padded,
+        end,
+    };
+    Ok((value, form))
 }
 
-#[tracing::instrument(ret(level = tracing::Level::DEBUG))]
 pub(crate) fn encode(value: &str) -> String {
-    let mut value = BASE64_STANDARD.encode(value);
-    value.push_str("!END!");
-    urlencoding::encode(&value).into_owned()
+    encode_as(value, &Form::default())
+}
+
+pub(crate) fn encode_as(value: &str, form: &Form) -> String {
+    let engine = match (form.url_safe, form.padded) {
+        (false, true) => &BASE64_STANDARD,
+        (false, false) => &BASE64_STANDARD_NO_PAD,
+        (true, true) => &BASE64_URL_SAFE,
+        (true, false) => &BASE64_URL_SAFE_NO_PAD,
+    };
+    let mut value = engine.encode(value);
+    if form.end {
+        value.push_str(END);
+    }
+    for _ in 0..form.url_encoded {
+        value = urlencoding::encode(&value).into_owned();
+    }
+    value
+}
+
+/// Decoding engines that accept both padded and unpadded base64.
+const STANDARD: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+fn strip_whitespace(value: Cow<'_, str>) -> Cow<'_, str> {
+    if value.contains(char::is_whitespace) {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    } else {
+        value
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::Form;
     use rand::distributions::DistString;
 
     #[test]
@@ -26,4 +125,39 @@ mod tests {
         let value = rand::distributions::Standard.sample_string(&mut rng, 4096);
         assert_eq!(super::decode(&super::encode(&value)).unwrap(), value,);
     }
+
+    #[test]
+    fn test_form() {
+        let value = include_str!("samples/00.txt").trim();
+        let (text, form) = super::decode_with_form(value).unwrap();
+        assert_eq!(form, Form::default());
+        assert_eq!(super::encode_as(&text, &form), value);
+
+        for form in [
+            Form {
+                url_encoded: 2,
+                ..Form::default()
+            },
+            Form {
+                url_encoded: 0,
+                url_safe: true,
+                padded: false,
+                end: false,
+            },
+        ] {
+            let code = super::encode_as("a save~~~!", &form);
+            assert_eq!(
+                super::decode_with_form(&code).unwrap(),
+                ("a save~~~!".into(), form)
+            );
+        }
+
+        let (text, _) = super::decode_with_form("YSBz\n YXZl%0A").unwrap();
+        assert_eq!(text, "a save");
+        let error = super::decode("YSBz*YXZl").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "base64 decoding: Invalid symbol 42, offset 4."
+        );
+    }
 }
@@ -1,18 +1,71 @@
+mod extra;
+mod lazy;
+mod lenient;
 mod none_as;
+mod seq;
+mod split;
 mod standard;
 mod timestamp;
 
 use crate::error::Error;
-pub(crate) use cookieclicker_save_derive::Format;
-pub(crate) use none_as::{NoneAsEmpty, NoneAsNegative, NoneAsZero};
-pub(crate) use standard::Standard;
+pub use cookieclicker_save_derive::Format;
+pub use extra::Extra;
+pub use lazy::{Elements, Lazy};
+pub(crate) use lenient::collect;
+pub use none_as::{NoneAsEmpty, NoneAsNegative, NoneAsZero};
+pub use seq::{Chars, Seq, Sequence};
+pub use standard::Standard;
 use std::fmt;
-pub(crate) use timestamp::Timestamp;
+pub use timestamp::Timestamp;
 
-pub(crate) trait Format<'a, T> {
+/// A codec between values of type `T` and their text in a save, such as [`Standard`].
+///
+/// Codecs for records are usually derived. The container attributes are
+/// `split = ','`, the field separator, `trailing = true`, for a separator after every field,
+/// and `crate = "path"`, for the path of this crate when it is re-exported. Without `split`,
+/// each field is a single character. The field attributes are:
+///
+/// - `with = Codec`, to use another codec than [`Standard`].
+/// - `flatten`, to leave the field out of error paths.
+/// - `default` or `default = expr`, for fields that may be missing at the end of a record.
+/// - `omit_default`, with `default`, to leave out trailing fields that hold their defaults.
+/// - `optional`, for `Option` fields, the same as `default, omit_default`.
+/// - `extra`, for a field of type [`Extra`] that tracks missing and unknown trailing fields.
+/// - `seq(split = ',', width = 2, trailing = true)`, for [`Sequence`] fields, see [`Seq`].
+///   Without `split`, it is [`Chars`]. `with` then applies to the elements.
+///
+/// Tuple structs with a single field and no `split` are encoded as that field. Enums of unit
+/// variants are encoded as their discriminant, or their `code = 1` or `code = "text"`, with an
+/// optional `other` variant holding any other value that encodes back to the same text. The variant marked `fallback` is the
+/// [`fallback`](Format::fallback).
+///
+/// ```
+/// use cookieclicker_save::format::{self, Format};
+///
+/// #[derive(Debug, PartialEq, format::Format)]
+/// #[format(split = ':')]
+/// struct Mod {
+///     name: String,
+///     #[format(seq(split = ','))]
+///     values: Vec<u64>,
+///     #[format(optional, with = format::NoneAsEmpty)]
+///     comment: Option<String>,
+/// }
+///
+/// let value: Mod = format::Standard::decode("cheats:1,2,3").unwrap();
+/// assert_eq!(value.values, [1, 2, 3]);
+/// assert_eq!(format::Standard::display(&value).to_string(), "cheats:1,2,3");
+/// format::check_inverse::<format::Standard, Mod>("cheats:1,2,3:hi").unwrap();
+/// ```
+pub trait Format<'a, T> {
     fn decode(value: &'a str) -> Result<T, Error>;
     fn encode(value: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result;
 
+    /// A stand-in for values that fail to decode in lenient mode.
+    fn fallback() -> Option<T> {
+        None
+    }
+
     fn display<'b>(value: &'b T) -> impl fmt::Display + 'b
     where
         Self: 'b,
@@ -33,8 +86,10 @@ pub(crate) trait Format<'a, T> {
         Display(value, Self::encode)
     }
 
-    #[cfg(test)]
-    fn check_inverse_hook<'b>(_: &'b str) -> anyhow::Result<()>
+    /// Checks the parts of `value` before the whole, so that [`check_inverse`] can point at
+    /// the part that does not round-trip.
+    #[doc(hidden)]
+    fn check_inverse_hook<'b>(_: &'b str) -> __private::CheckResult
     where
         'b: 'a,
         Self: 'b,
@@ -43,25 +98,118 @@ pub(crate) trait Format<'a, T> {
     }
 }
 
-pub(crate) fn chars(value: &str) -> impl Iterator<Item = &str> {
-    value
-        .char_indices()
-        .map(|(offset, c)| &value[offset..offset + c.len_utf8()])
-}
-
-#[cfg(test)]
+/// Checks that `value` encodes back to itself after decoding it with `T`.
 #[tracing::instrument(err)]
-pub(crate) fn check_inverse<'a, 'b, T, U>(value: &'b str) -> anyhow::Result<()>
+pub fn check_inverse<'a, 'b, T, U>(value: &'b str) -> __private::CheckResult
 where
     'b: 'a,
     T: Format<'a, U> + 'b,
 {
     T::check_inverse_hook(value)?;
     let actual = T::display(&T::decode(value)?).to_string();
-    let expected = value.to_owned();
-    anyhow::ensure!(
-        actual == expected,
-        "actual = {actual:?}, expected = {expected:?}",
-    );
+    if actual != value {
+        return Err(format!("actual = {actual:?}, expected = {value:?}").into());
+    }
     Ok(())
 }
+
+/// Used by the code generated by the derive.
+#[doc(hidden)]
+pub mod __private {
+    pub use super::lenient::{field, leftover, recover};
+    pub use super::split::Split;
+    pub use crate::error::Error;
+
+    pub type CheckResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{self as format, Format};
+    use crate::error::Error;
+    use crate::{DragonAura, DragonLevel, Season};
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_derive() {
+        #[derive(Debug, PartialEq, format::Format)]
+        #[format(split = ',')]
+        struct Pair(DragonLevel, Season);
+
+        #[derive(Debug, PartialEq, format::Format)]
+        enum Mode {
+            Off,
+            On = 3,
+        }
+
+        let value: Pair = format::Standard::decode("5,easter").unwrap();
+        assert_eq!(
+            value,
+            Pair(
+                DragonLevel::Trained(DragonAura::BreathOfMilk),
+                Season::Easter
+            )
+        );
+        format::check_inverse::<'_, '_, format::Standard, Pair>("5,easter").unwrap();
+        let error = <format::Standard as Format<Pair>>::decode("x,easter").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "0: invalid digit found in string at byte 0 (\"x\")"
+        );
+
+        let value: Mode = format::Standard::decode("3").unwrap();
+        assert_eq!(value, Mode::On);
+        format::check_inverse::<'_, '_, format::Standard, Mode>("0").unwrap();
+        let error = <format::Standard as Format<Mode>>::decode("1").unwrap_err();
+        assert!(matches!(error, Error::InvalidData));
+        let error = <format::Standard as Format<Mode>>::decode("03").unwrap_err();
+        assert!(matches!(error, Error::InvalidData));
+
+        #[derive(Debug, PartialEq, format::Format)]
+        enum Level {
+            #[format(code = 1)]
+            Low,
+            #[format(code = 2)]
+            High,
+            #[format(other)]
+            Other(String),
+        }
+
+        let value: Level = format::Standard::decode("2").unwrap();
+        assert_eq!(value, Level::High);
+        for value in ["01", "+1", "1.0", "-0"] {
+            let decoded: Level = format::Standard::decode(value).unwrap();
+            assert_eq!(decoded, Level::Other(value.into()));
+            format::check_inverse::<'_, '_, format::Standard, Level>(value).unwrap();
+        }
+
+        #[derive(Debug, PartialEq, format::Format)]
+        #[format(split = ':')]
+        struct Record {
+            id: u64,
+            #[format(default = 10)]
+            level: u64,
+            #[format(default, omit_default)]
+            name: String,
+            #[format(optional, with = format::NoneAsEmpty)]
+            season: Option<Season>,
+        }
+
+        let value: Record = format::Standard::decode("3").unwrap();
+        assert_eq!(
+            value,
+            Record {
+                id: 3,
+                level: 10,
+                name: String::new(),
+                season: None,
+            },
+        );
+        assert_eq!(format::Standard::display(&value).to_string(), "3:10");
+        for value in ["3:10", "3:10:x", "3:10::easter"] {
+            format::check_inverse::<'_, '_, format::Standard, Record>(value).unwrap();
+        }
+        let error = <format::Standard as Format<Record>>::decode("").unwrap_err();
+        assert!(matches!(error.kind(), Error::Int(_)));
+    }
+}
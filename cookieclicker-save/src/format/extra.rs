@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Bookkeeping for records that may be shorter or longer than the known layout.
+/// Records without it fail to decode when there is data after their last field.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Extra {
+    /// Segments following the last known field, kept verbatim.
+    pub unknown: Option<String>,
+    /// Known fields that were missing and decoded as their defaults.
+    /// They are omitted on encode, along with the fields that follow them,
+    /// as long as all of those still have their default values.
+    pub defaulted: Vec<String>,
+    /// Known fields that are omitted on encode whatever their values, along with the fields
+    /// that follow them, e.g. to write an older layout.
+    #[serde(default)]
+    pub omitted: Vec<String>,
+}
+
+impl Extra {
+    pub fn is_defaulted(&self, field: &str) -> bool {
+        self.defaulted.iter().any(|v| v == field)
+    }
+
+    pub fn is_omitted(&self, field: &str) -> bool {
+        self.omitted.iter().any(|v| v == field)
+    }
+}
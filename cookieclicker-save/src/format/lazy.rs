@@ -0,0 +1,73 @@
+use super::seq::Sequence;
+use super::{Format, Standard};
+use crate::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A `T` that is kept as its text, borrowed from the save, until it is decoded with `F`.
+/// It is encoded as the original text.
+pub struct Lazy<'a, T, F = Standard> {
+    raw: &'a str,
+    _marker: PhantomData<fn() -> (T, F)>,
+}
+
+/// Codecs of sequences that can decode their elements one at a time, see [`Lazy::iter`].
+pub trait Elements<'a, T> {
+    fn elements(value: &'a str) -> impl Iterator<Item = Result<T, Error>> + 'a;
+}
+
+impl<'a, T, F> Lazy<'a, T, F> {
+    pub fn new(raw: &'a str) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    /// Decodes the value. Offsets in errors are relative to [`raw`](Self::raw).
+    pub fn decode(&self) -> Result<T, Error>
+    where
+        F: Format<'a, T>,
+    {
+        F::decode(self.raw)
+    }
+}
+
+impl<'a, S, F> Lazy<'a, S, F>
+where
+    S: Sequence,
+    F: Elements<'a, S::Item>,
+{
+    /// Decodes the elements one at a time, without collecting them.
+    pub fn iter(&self) -> impl Iterator<Item = Result<S::Item, Error>> + 'a {
+        F::elements(self.raw)
+    }
+}
+
+impl<T, F> Clone for Lazy<'_, T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for Lazy<'_, T, F> {}
+
+impl<T, F> fmt::Debug for Lazy<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Lazy").field(&self.raw).finish()
+    }
+}
+
+impl<'a, T, F> Format<'a, Lazy<'a, T, F>> for Standard {
+    fn decode(value: &'a str) -> Result<Lazy<'a, T, F>, Error> {
+        Ok(Lazy::new(value))
+    }
+
+    fn encode(value: &Lazy<'a, T, F>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(value.raw)
+    }
+}
@@ -0,0 +1,64 @@
+use super::Format;
+use crate::error::Error;
+use std::cell::RefCell;
+use std::mem;
+
+thread_local! {
+    // `Some` while a lenient decode is running on this thread.
+    static DIAGNOSTICS: RefCell<Option<Vec<Error>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` in lenient mode, returning the errors that were recovered from.
+pub(crate) fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Error>) {
+    let outer = DIAGNOSTICS.replace(Some(Vec::new()));
+    let value = f();
+    let diagnostics = DIAGNOSTICS.replace(outer).unwrap_or_default();
+    (value, diagnostics)
+}
+
+/// Decodes `value` with `F`, attributing errors to it via `context`.
+/// In lenient mode, an error is recorded and replaced with `F::fallback()` if there is one.
+pub fn field<'a, F, T>(value: &'a str, context: impl Fn(Error) -> Error) -> Result<T, Error>
+where
+    F: Format<'a, T>,
+{
+    let mark = DIAGNOSTICS.with_borrow(|diagnostics| diagnostics.as_ref().map(Vec::len));
+    let result = F::decode(value).map_err(&context);
+    if let Some(mark) = mark {
+        DIAGNOSTICS.with_borrow_mut(|diagnostics| {
+            for error in diagnostics.iter_mut().flatten().skip(mark) {
+                *error = context(mem::replace(error, Error::InvalidData));
+            }
+        });
+    }
+    recover::<F, T>(result)
+}
+
+/// Reports `rest`, the part of `value` left over after the last field of a record that has no
+/// `extra` field to keep it. In lenient mode, the error is recorded and `rest` is dropped.
+pub fn leftover(value: &str, rest: &str) -> Result<(), Error> {
+    let error = Error::TrailingData.within(value, rest);
+    DIAGNOSTICS.with_borrow_mut(|diagnostics| match diagnostics {
+        Some(diagnostics) => {
+            diagnostics.push(error);
+            Ok(())
+        }
+        None => Err(error),
+    })
+}
+
+/// Like `field`, for an error that happened before there was anything to decode.
+pub fn recover<'a, F, T>(result: Result<T, Error>) -> Result<T, Error>
+where
+    F: Format<'a, T>,
+{
+    result.or_else(|error| {
+        DIAGNOSTICS.with_borrow_mut(|diagnostics| match (diagnostics, F::fallback()) {
+            (Some(diagnostics), Some(value)) => {
+                diagnostics.push(error);
+                Ok(value)
+            }
+            _ => Err(error),
+        })
+    })
+}
@@ -5,13 +5,13 @@ use std::marker::PhantomData;
 
 macro_rules! none_as {
     ($name:ident, $none:literal) => {
-        pub(crate) struct $name<T = Standard>(PhantomData<T>);
+        #[doc = concat!("An `Option` that is `", $none, "` when `None`, and `T` otherwise.")]
+        pub struct $name<T = Standard>(PhantomData<T>);
 
         impl<'a, T, U> Format<'a, Option<T>> for $name<U>
         where
             U: Format<'a, T>,
         {
-            #[tracing::instrument(err)]
             fn decode(value: &'a str) -> Result<Option<T>, Error> {
                 if value == $none {
                     Ok(None)
@@ -27,6 +27,10 @@ macro_rules! none_as {
                     write!(f, $none)
                 }
             }
+
+            fn fallback() -> Option<Option<T>> {
+                Some(None)
+            }
         }
     };
 }
@@ -0,0 +1,50 @@
+// Generates numbers.txt, the bits of each number and its string in JavaScript.
+// Run with `node numbers.js > numbers.txt`.
+
+const view = new DataView(new ArrayBuffer(8));
+const bits = (value) => {
+  view.setFloat64(0, value);
+  return view.getBigUint64(0).toString(16).padStart(16, "0");
+};
+const fromBits = (value) => {
+  view.setBigUint64(0, value);
+  return view.getFloat64(0);
+};
+
+// xorshift64, so that the corpus is the same on every run.
+let state = 0x2545f4914f6cdd1dn;
+const random = () => {
+  state ^= (state << 13n) & 0xffffffffffffffffn;
+  state ^= state >> 7n;
+  state ^= (state << 17n) & 0xffffffffffffffffn;
+  return state;
+};
+
+const values = [
+  NaN, Infinity, -Infinity, 0, -0, 1, -1, 0.1, 0.2, 0.1 + 0.2, 1 / 3, 2 / 3, 17, 17.2,
+  Number.MAX_VALUE, Number.MIN_VALUE, -Number.MIN_VALUE, Number.EPSILON,
+  Number.MAX_SAFE_INTEGER, Number.MAX_SAFE_INTEGER + 2, 2 ** 53, 2 ** 64, 2 ** -1074,
+  2.2250738585072014e-308, 2.225073858507201e-308, 5e-324, 1.7976931348623157e308,
+  1e21, 1e21 - 65536, 999999999999999900000, 1e-6, 1e-7, 0.000001, 0.0000015, 9.5e-7,
+  123456789012345680000, 1000000000000000100, 3.1622776601683794e21, 4.35, 0.5, 1.5,
+];
+for (let e = -330; e <= 310; e++) {
+  values.push(Number(`1e${e}`), Number(`1.5e${e}`), Number(`9.999999999999999e${e}`));
+}
+for (let e = -1074; e <= 1023; e++) {
+  values.push(2 ** e);
+}
+for (let i = 0; i < 500; i++) {
+  // Uniformly distributed bits, which are mostly very large or small.
+  values.push(fromBits(random()));
+}
+for (let i = 0; i < 500; i++) {
+  // Magnitudes as found in saves, e.g. cookies and timers.
+  const scale = 10 ** (Number(random() % 40n) - 10);
+  const value = (Number(random() >> 11n) / 2 ** 53) * scale;
+  values.push(value, Math.round(value), Math.floor(value * 100) / 100);
+}
+
+for (const value of values) {
+  console.log(`${bits(value)} ${value}`);
+}
@@ -0,0 +1,265 @@
+use super::__private::CheckResult;
+use super::lazy::Elements;
+use super::lenient::{field, recover};
+use super::split::Split;
+use super::Format;
+use crate::error::Error;
+use std::borrow::Cow;
+use std::fmt;
+use std::iter;
+use std::marker::PhantomData;
+
+/// A collection that is decoded and encoded element by element, see [`Seq`] and [`Chars`].
+pub trait Sequence {
+    type Item;
+
+    fn from_vec(value: Vec<Self::Item>) -> Self;
+    fn as_slice(&self) -> &[Self::Item];
+}
+
+impl<T> Sequence for Vec<T> {
+    type Item = T;
+
+    fn from_vec(value: Vec<T>) -> Self {
+        value
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Sequence for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn from_vec(value: Vec<T>) -> Self {
+        Cow::Owned(value)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+/// Elements decoded with `F`, each from `WIDTH` segments separated by `SPLIT`.
+/// With `TRAILING`, every element is followed by `SPLIT`. The empty string is the empty sequence.
+pub struct Seq<F, const SPLIT: char, const WIDTH: usize = 1, const TRAILING: bool = false>(
+    PhantomData<F>,
+);
+
+/// Elements decoded with `F`, each from `WIDTH` characters.
+pub struct Chars<F, const WIDTH: usize = 1>(PhantomData<F>);
+
+impl<'a, F, S, const SPLIT: char, const WIDTH: usize, const TRAILING: bool> Format<'a, S>
+    for Seq<F, SPLIT, WIDTH, TRAILING>
+where
+    F: Format<'a, S::Item>,
+    S: Sequence,
+{
+    fn decode(value: &'a str) -> Result<S, Error> {
+        decode::<F, S>(value, split::<SPLIT, TRAILING>(value), WIDTH)
+    }
+
+    fn encode(value: &S, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        encode::<F, S>(value, Some(SPLIT), TRAILING, f)
+    }
+
+    fn check_inverse_hook<'b>(value: &'b str) -> CheckResult
+    where
+        'b: 'a,
+        Self: 'b,
+    {
+        check_inverse_hook::<F, S>(value, split::<SPLIT, TRAILING>(value), WIDTH)
+    }
+}
+
+impl<'a, F, S, const WIDTH: usize> Format<'a, S> for Chars<F, WIDTH>
+where
+    F: Format<'a, S::Item>,
+    S: Sequence,
+{
+    fn decode(value: &'a str) -> Result<S, Error> {
+        decode::<F, S>(value, Split::chars(value), WIDTH)
+    }
+
+    fn encode(value: &S, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        encode::<F, S>(value, None, false, f)
+    }
+
+    fn check_inverse_hook<'b>(value: &'b str) -> CheckResult
+    where
+        'b: 'a,
+        Self: 'b,
+    {
+        check_inverse_hook::<F, S>(value, Split::chars(value), WIDTH)
+    }
+}
+
+impl<'a, F, T, const SPLIT: char, const WIDTH: usize, const TRAILING: bool> Elements<'a, T>
+    for Seq<F, SPLIT, WIDTH, TRAILING>
+where
+    F: Format<'a, T> + 'a,
+    T: 'a,
+{
+    fn elements(value: &'a str) -> impl Iterator<Item = Result<T, Error>> + 'a {
+        elements::<F, T>(value, split::<SPLIT, TRAILING>(value), WIDTH)
+    }
+}
+
+impl<'a, F, T, const WIDTH: usize> Elements<'a, T> for Chars<F, WIDTH>
+where
+    F: Format<'a, T> + 'a,
+    T: 'a,
+{
+    fn elements(value: &'a str) -> impl Iterator<Item = Result<T, Error>> + 'a {
+        elements::<F, T>(value, Split::chars(value), WIDTH)
+    }
+}
+
+fn split<const SPLIT: char, const TRAILING: bool>(value: &str) -> Split<'_> {
+    let mut split = Split::new(value, SPLIT, TRAILING);
+    if value.is_empty() {
+        // Unlike `str::split`, which yields a single empty segment.
+        split.by_ref().for_each(drop);
+    }
+    split
+}
+
+/// Groups `segments` of `value` by `width`, yielding each group as a slice of `value`
+/// and whether it is complete.
+fn groups<'a>(
+    value: &'a str,
+    mut segments: Split<'a>,
+    width: usize,
+) -> impl Iterator<Item = (&'a str, bool)> {
+    let offset = move |segment: &str| segment.as_ptr() as usize - value.as_ptr() as usize;
+    iter::from_fn(move || {
+        let first = segments.next()?;
+        let start = offset(first);
+        let mut end = start + first.len();
+        for _ in 1..width {
+            let Some(segment) = segments.next() else {
+                return Some((&value[start..end], false));
+            };
+            end = offset(segment) + segment.len();
+        }
+        Some((&value[start..end], true))
+    })
+}
+
+/// Decodes the elements of `value` strictly, unlike `decode` which recovers in lenient mode.
+fn elements<'a, F, T>(
+    value: &'a str,
+    segments: Split<'a>,
+    width: usize,
+) -> impl Iterator<Item = Result<T, Error>> + 'a
+where
+    F: Format<'a, T> + 'a,
+    T: 'a,
+{
+    groups(value, segments, width)
+        .enumerate()
+        .map(move |(i, (element, complete))| {
+            let error = |error: Error| error.index(i, value, element);
+            if complete {
+                F::decode(element).map_err(error)
+            } else {
+                Err(error(Error::InsufficientData))
+            }
+        })
+}
+
+fn decode<'a, F, S>(value: &'a str, segments: Split<'a>, width: usize) -> Result<S, Error>
+where
+    F: Format<'a, S::Item>,
+    S: Sequence,
+{
+    groups(value, segments, width)
+        .enumerate()
+        .map(|(i, (element, complete))| {
+            if complete {
+                field::<F, _>(element, |error| error.index(i, value, element))
+            } else {
+                recover::<F, _>(Err(Error::InsufficientData.index(i, value, element)))
+            }
+        })
+        .collect::<Result<_, _>>()
+        .map(S::from_vec)
+}
+
+fn encode<'a, F, S>(
+    value: &S,
+    split: Option<char>,
+    trailing: bool,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result
+where
+    F: Format<'a, S::Item>,
+    S: Sequence,
+{
+    for (i, v) in value.as_slice().iter().enumerate() {
+        if let Some(split) = split.filter(|_| i > 0 && !trailing) {
+            fmt::Display::fmt(&split, f)?;
+        }
+        F::encode(v, f)?;
+        if let Some(split) = split.filter(|_| trailing) {
+            fmt::Display::fmt(&split, f)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_inverse_hook<'a, 'b, F, S>(
+    value: &'b str,
+    segments: Split<'b>,
+    width: usize,
+) -> CheckResult
+where
+    'b: 'a,
+    F: Format<'a, S::Item> + 'b,
+    S: Sequence,
+{
+    for (i, (element, complete)) in groups(value, segments, width).enumerate() {
+        if !complete {
+            return Err(Error::InsufficientData.into());
+        }
+        super::check_inverse::<'a, 'b, F, S::Item>(element)
+            .map_err(|error| format!("[{i}]: {error}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chars, Seq};
+    use crate::format::{self, Format, NoneAsZero, Standard};
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_seq() {
+        type Pairs = Seq<Standard, ':', 2, true>;
+        let value: Vec<String> = Pairs::decode("1:2:3:4:").unwrap();
+        assert_eq!(value, ["1:2", "3:4"]);
+        let value: Vec<String> = Pairs::decode("").unwrap();
+        assert!(value.is_empty());
+        format::check_inverse::<'_, '_, Pairs, Vec<String>>("1:2:3:4:").unwrap();
+        let error = <Pairs as Format<Vec<String>>>::decode("1:2:3:").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "[1]: insufficient data at byte 4 (\"3\")"
+        );
+
+        type Slots = Seq<NoneAsZero, '/'>;
+        let value: Vec<Option<u64>> = Slots::decode("0/5").unwrap();
+        assert_eq!(value, [None, Some(5)]);
+        format::check_inverse::<'_, '_, Slots, Vec<Option<u64>>>("0/5").unwrap();
+
+        let value: Vec<String> = Chars::<Standard, 3>::decode("abcdef").unwrap();
+        assert_eq!(value, ["abc", "def"]);
+        let error = <Chars<Standard> as Format<Vec<bool>>>::decode("1012").unwrap_err();
+        assert_eq!(error.to_string(), "[3]: invalid data at byte 3 (\"2\")");
+    }
+}
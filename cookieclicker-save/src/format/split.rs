@@ -0,0 +1,86 @@
+/// Like `str::split`, but keeps track of the unconsumed remainder.
+/// With `trailing`, a single trailing separator is not yielded as an empty segment.
+#[derive(Debug)]
+pub struct Split<'a> {
+    value: &'a str,
+    split: Option<char>,
+    offset: Option<usize>,
+}
+
+impl<'a> Split<'a> {
+    pub fn new(value: &'a str, split: char, trailing: bool) -> Self {
+        let value = if trailing {
+            value.strip_suffix(split).unwrap_or(value)
+        } else {
+            value
+        };
+        Self {
+            value,
+            split: Some(split),
+            offset: Some(0),
+        }
+    }
+
+    pub fn chars(value: &'a str) -> Self {
+        Self {
+            value,
+            split: None,
+            offset: Some(0),
+        }
+    }
+
+    pub fn remainder(&self) -> Option<&'a str> {
+        let offset = self.offset?;
+        if self.split.is_none() && offset == self.value.len() {
+            None
+        } else {
+            Some(&self.value[offset..])
+        }
+    }
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset?;
+        let rest = &self.value[offset..];
+        if let Some(split) = self.split {
+            if let Some(i) = rest.find(split) {
+                self.offset = Some(offset + i + split.len_utf8());
+                Some(&rest[..i])
+            } else {
+                self.offset = None;
+                Some(rest)
+            }
+        } else {
+            let len = rest.chars().next()?.len_utf8();
+            self.offset = Some(offset + len);
+            Some(&rest[..len])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Split;
+
+    #[test]
+    fn test_split() {
+        let mut split = Split::new("a;b;c;", ';', true);
+        assert_eq!(split.by_ref().take(2).collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(split.remainder(), Some("c"));
+        assert_eq!(split.collect::<Vec<_>>(), ["c"]);
+
+        let mut split = Split::new("a;b;", ';', false);
+        assert_eq!(split.by_ref().take(2).collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(split.remainder(), Some(""));
+
+        let mut split = Split::chars("0110");
+        assert_eq!(
+            split.by_ref().take(4).collect::<Vec<_>>(),
+            ["0", "1", "1", "0"]
+        );
+        assert_eq!(split.remainder(), None);
+    }
+}
@@ -3,10 +3,10 @@ use crate::error::Error;
 use std::borrow::Cow;
 use std::fmt;
 
-pub(crate) struct Standard;
+/// The format of each type as the game writes it, e.g. `0`/`1` for booleans.
+pub struct Standard;
 
 impl Format<'_, ()> for Standard {
-    #[tracing::instrument(err)]
     fn decode(_: &str) -> Result<(), Error> {
         Ok(())
     }
@@ -14,10 +14,13 @@ impl Format<'_, ()> for Standard {
     fn encode(_: &(), _: &mut fmt::Formatter<'_>) -> fmt::Result {
         Ok(())
     }
+
+    fn fallback() -> Option<()> {
+        Some(())
+    }
 }
 
 impl Format<'_, bool> for Standard {
-    #[tracing::instrument(err)]
     fn decode(value: &str) -> Result<bool, Error> {
         match value {
             "0" => Ok(false),
@@ -29,10 +32,13 @@ impl Format<'_, bool> for Standard {
     fn encode(value: &bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", if *value { 1 } else { 0 })
     }
+
+    fn fallback() -> Option<bool> {
+        Some(false)
+    }
 }
 
 impl<'a> Format<'a, &'a str> for Standard {
-    #[tracing::instrument(err)]
     fn decode(value: &'a str) -> Result<&'a str, Error> {
         Ok(value)
     }
@@ -43,7 +49,6 @@ impl<'a> Format<'a, &'a str> for Standard {
 }
 
 impl Format<'_, String> for Standard {
-    #[tracing::instrument(err)]
     fn decode(value: &str) -> Result<String, Error> {
         Ok(value.to_owned())
     }
@@ -51,10 +56,13 @@ impl Format<'_, String> for Standard {
     fn encode(value: &String, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{value}")
     }
+
+    fn fallback() -> Option<String> {
+        Some(String::new())
+    }
 }
 
 impl<'a> Format<'a, Cow<'a, str>> for Standard {
-    #[tracing::instrument(err)]
     fn decode(value: &'a str) -> Result<Cow<'a, str>, Error> {
         Ok(value.into())
     }
@@ -62,28 +70,73 @@ impl<'a> Format<'a, Cow<'a, str>> for Standard {
     fn encode(value: &Cow<'a, str>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{value}")
     }
+
+    fn fallback() -> Option<Cow<'a, str>> {
+        Some(Cow::Borrowed(""))
+    }
 }
 
 impl Format<'_, f64> for Standard {
-    #[tracing::instrument(err)]
     fn decode(value: &str) -> Result<f64, Error> {
         Ok(value.parse()?)
     }
 
     fn encode(value: &f64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toString#return_value
-        if value.abs() >= 1e21 || value.abs() < 1e-6 {
-            let exp = format!("{value:e}");
-            let (m, e) = exp.split_once('e').unwrap_or((&exp, "0"));
-            if ("0", "0") == (m, e) {
-                write!(f, "0")
-            } else if e.starts_with(['+', '-']) {
-                write!(f, "{m}e{e}")
-            } else {
-                write!(f, "{m}e+{e}")
-            }
+        to_js_string(*value, f)
+    }
+
+    fn fallback() -> Option<f64> {
+        Some(0.)
+    }
+}
+
+/// Writes `value` as JavaScript's `Number.prototype.toString` does.
+///
+/// https://tc39.es/ecma262/#sec-numeric-types-number-tostring
+fn to_js_string(value: f64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if value.is_nan() {
+        return f.write_str("NaN");
+    }
+    if value == 0. {
+        // Including -0.
+        return f.write_str("0");
+    }
+    if value.is_sign_negative() {
+        f.write_str("-")?;
+    }
+    if value.is_infinite() {
+        return f.write_str("Infinity");
+    }
+
+    // The specification asks for the fewest digits that round-trip, and of those the closest
+    // to `value`, ties to even. `{:e}` finds how many, but rounds ties up, so the exactly
+    // rounded `{:.*e}` is preferred. That one may not round-trip next to a power of two.
+    let shortest = format!("{:e}", value.abs());
+    let k = shortest.find('e').unwrap_or(shortest.len()) - usize::from(shortest.contains('.'));
+    let closest = format!("{:.*e}", k - 1, value.abs());
+    let exp = if closest.parse() == Ok(value.abs()) {
+        closest
+    } else {
+        shortest
+    };
+    let (m, e) = exp.split_once('e').unwrap_or((&exp, "0"));
+    let digits = m.replace('.', "");
+    let k = digits.len() as i32;
+    let n = e.parse::<i32>().unwrap_or_default() + 1;
+    if k <= n && n <= 21 {
+        write!(f, "{digits}{:0>1$}", "", (n - k) as usize)
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        write!(f, "{int}.{frac}")
+    } else if -6 < n && n <= 0 {
+        write!(f, "0.{:0>1$}{digits}", "", -n as usize)
+    } else {
+        let (int, frac) = digits.split_at(1);
+        let sign = if n > 0 { '+' } else { '-' };
+        if frac.is_empty() {
+            write!(f, "{int}e{sign}{}", (n - 1).abs())
         } else {
-            write!(f, "{value}")
+            write!(f, "{int}.{frac}e{sign}{}", (n - 1).abs())
         }
     }
 }
@@ -91,7 +144,6 @@ impl Format<'_, f64> for Standard {
 macro_rules! display_from_str {
     ($ty:ty) => {
         impl Format<'_, $ty> for Standard {
-            #[tracing::instrument(err)]
             fn decode(value: &str) -> Result<$ty, Error> {
                 Ok(value.parse()?)
             }
@@ -99,15 +151,20 @@ macro_rules! display_from_str {
             fn encode(value: &$ty, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "{value}")
             }
+
+            fn fallback() -> Option<$ty> {
+                Some(0)
+            }
         }
     };
 }
+display_from_str!(i64);
 display_from_str!(u64);
 display_from_str!(usize);
 
 #[cfg(test)]
 mod tests {
-    use super::super::check_inverse;
+    use super::super::{check_inverse, Format};
     use super::Standard;
 
     #[test]
@@ -118,5 +175,24 @@ mod tests {
         check_inverse::<'_, '_, Standard, f64>("1000000000000000100").unwrap();
         check_inverse::<'_, '_, Standard, f64>("17").unwrap();
         check_inverse::<'_, '_, Standard, f64>("17.2").unwrap();
+        check_inverse::<'_, '_, Standard, f64>("NaN").unwrap();
+        check_inverse::<'_, '_, Standard, f64>("-Infinity").unwrap();
+        assert_eq!(Standard::display(&-0.).to_string(), "0");
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_f64_corpus() {
+        // Generated by `numbers.js`.
+        for line in include_str!("numbers.txt").lines() {
+            let (bits, expected) = line.split_once(' ').unwrap();
+            let value = f64::from_bits(u64::from_str_radix(bits, 16).unwrap());
+            assert_eq!(Standard::display(&value).to_string(), expected, "{bits}");
+            let decoded: f64 = Standard::decode(expected).unwrap();
+            assert!(
+                decoded.to_bits() == value.to_bits() || value == 0. || value.is_nan(),
+                "{bits}",
+            );
+        }
     }
 }
@@ -3,10 +3,10 @@ use crate::error::Error;
 use chrono::{DateTime, Utc};
 use std::fmt;
 
-pub(crate) struct Timestamp;
+/// A date as milliseconds since the Unix epoch.
+pub struct Timestamp;
 
 impl Format<'_, DateTime<Utc>> for Timestamp {
-    #[tracing::instrument(err)]
     fn decode(value: &str) -> Result<DateTime<Utc>, Error> {
         DateTime::from_timestamp_millis(value.parse()?).ok_or(Error::InvalidData)
     }
@@ -14,4 +14,8 @@ impl Format<'_, DateTime<Utc>> for Timestamp {
     fn encode(value: &DateTime<Utc>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", value.timestamp_millis())
     }
+
+    fn fallback() -> Option<DateTime<Utc>> {
+        Some(DateTime::UNIX_EPOCH)
+    }
 }
@@ -1,95 +1,200 @@
-use crate::error::Error;
 use crate::format;
+use crate::Building;
 use serde::{Deserialize, Serialize};
-use std::fmt;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
+#[format(split = ',')]
 pub struct GameBuff {
     pub effect_id: usize,
     pub maximum_time: u64,
     pub time_remaining: u64,
+    #[format(optional, with = format::NoneAsEmpty)]
+    #[serde(with = "crate::float::option")]
     pub argument1: Option<f64>,
+    #[format(optional, with = format::NoneAsEmpty)]
     pub argument2: Option<usize>,
+    #[format(optional, with = format::NoneAsEmpty)]
     pub argument3: Option<String>,
 }
 
-pub(crate) struct Custom;
+/// The effect of a [`GameBuff`], in the order the game registers its buff types.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum Buff {
+    Frenzy {
+        multiplier: f64,
+    },
+    ElderFrenzy {
+        multiplier: f64,
+    },
+    Clot {
+        multiplier: f64,
+    },
+    DragonHarvest {
+        multiplier: f64,
+    },
+    EverythingMustGo {
+        discount: f64,
+    },
+    CursedFinger {
+        power: f64,
+    },
+    ClickFrenzy {
+        multiplier: f64,
+    },
+    Dragonflight {
+        multiplier: f64,
+    },
+    CookieStorm {
+        power: f64,
+    },
+    BuildingSpecial {
+        multiplier: f64,
+        building: Building,
+    },
+    BuildingDebuff {
+        divisor: f64,
+        building: Building,
+    },
+    SugarBlessing,
+    HagglerLuck {
+        power: f64,
+    },
+    HagglerMisery {
+        power: f64,
+    },
+    PixieLuck {
+        power: f64,
+    },
+    PixieMisery {
+        power: f64,
+    },
+    MagicAdept {
+        power: f64,
+    },
+    MagicInept {
+        power: f64,
+    },
+    Devastation {
+        multiplier: f64,
+    },
+    SugarFrenzy {
+        multiplier: f64,
+    },
+    Loan {
+        tier: usize,
+        multiplier: f64,
+    },
+    LoanInterest {
+        tier: usize,
+        multiplier: f64,
+    },
+    GiftedOut,
+    /// An effect ID that the crate does not know, or whose arguments do not fit its type.
+    Unknown(usize),
+}
+
+impl GameBuff {
+    pub fn buff(&self) -> Buff {
+        Buff::from_game_buff(self)
+    }
+}
+
+impl Buff {
+    pub fn from_game_buff(value: &GameBuff) -> Self {
+        let power = value.argument1;
+        let building = value.argument2.and_then(Building::from_id);
+        match (value.effect_id, power, building) {
+            (0, Some(multiplier), _) => Self::Frenzy { multiplier },
+            (1, Some(multiplier), _) => Self::ElderFrenzy { multiplier },
+            (2, Some(multiplier), _) => Self::Clot { multiplier },
+            (3, Some(multiplier), _) => Self::DragonHarvest { multiplier },
+            (4, Some(discount), _) => Self::EverythingMustGo { discount },
+            (5, Some(power), _) => Self::CursedFinger { power },
+            (6, Some(multiplier), _) => Self::ClickFrenzy { multiplier },
+            (7, Some(multiplier), _) => Self::Dragonflight { multiplier },
+            (8, Some(power), _) => Self::CookieStorm { power },
+            (9, Some(multiplier), Some(building)) => Self::BuildingSpecial {
+                multiplier,
+                building,
+            },
+            (10, Some(divisor), Some(building)) => Self::BuildingDebuff { divisor, building },
+            (11, _, _) => Self::SugarBlessing,
+            (12, Some(power), _) => Self::HagglerLuck { power },
+            (13, Some(power), _) => Self::HagglerMisery { power },
+            (14, Some(power), _) => Self::PixieLuck { power },
+            (15, Some(power), _) => Self::PixieMisery { power },
+            (16, Some(power), _) => Self::MagicAdept { power },
+            (17, Some(power), _) => Self::MagicInept { power },
+            (18, Some(multiplier), _) => Self::Devastation { multiplier },
+            (19, Some(multiplier), _) => Self::SugarFrenzy { multiplier },
+            (id @ (20 | 22 | 24), Some(multiplier), _) => Self::Loan {
+                tier: (id - 20) / 2 + 1,
+                multiplier,
+            },
+            (id @ (21 | 23 | 25), Some(multiplier), _) => Self::LoanInterest {
+                tier: (id - 20) / 2 + 1,
+                multiplier,
+            },
+            (26, _, _) => Self::GiftedOut,
+            (id, _, _) => Self::Unknown(id),
+        }
+    }
 
-impl format::Format<'_, Vec<GameBuff>> for Custom {
-    #[tracing::instrument(err)]
-    fn decode(value: &str) -> Result<Vec<GameBuff>, Error> {
-        if value.is_empty() {
-            Ok(Vec::new())
-        } else {
-            value
-                .trim_end_matches(';')
-                .split(';')
-                .map(|v| {
-                    let mut split = v.split(',');
-                    Ok(GameBuff {
-                        effect_id: {
-                            let _span = tracing::info_span!(stringify!(effect_id)).entered();
-                            format::Standard::decode(split.next().ok_or(Error::InsufficientData)?)?
-                        },
-                        maximum_time: {
-                            let _span = tracing::info_span!(stringify!(maximum_time)).entered();
-                            format::Standard::decode(split.next().ok_or(Error::InsufficientData)?)?
-                        },
-                        time_remaining: {
-                            let _span = tracing::info_span!(stringify!(time_remaining)).entered();
-                            format::Standard::decode(split.next().ok_or(Error::InsufficientData)?)?
-                        },
-                        argument1: {
-                            let _span = tracing::info_span!(stringify!(argument1)).entered();
-                            if let Some(v) = split.next() {
-                                format::NoneAsEmpty::<format::Standard>::decode(v)?
-                            } else {
-                                None
-                            }
-                        },
-                        argument2: {
-                            let _span = tracing::info_span!(stringify!(argument2)).entered();
-                            if let Some(v) = split.next() {
-                                format::NoneAsEmpty::<format::Standard>::decode(v)?
-                            } else {
-                                None
-                            }
-                        },
-                        argument3: {
-                            let _span = tracing::info_span!(stringify!(argument3)).entered();
-                            if let Some(v) = split.next() {
-                                format::NoneAsEmpty::<format::Standard>::decode(v)?
-                            } else {
-                                None
-                            }
-                        },
-                    })
-                })
-                .collect()
+    /// The multiplier applied to the cookies per second, if any.
+    pub fn cps_multiplier(&self) -> Option<f64> {
+        match *self {
+            Self::Frenzy { multiplier }
+            | Self::ElderFrenzy { multiplier }
+            | Self::Clot { multiplier }
+            | Self::DragonHarvest { multiplier }
+            | Self::BuildingSpecial { multiplier, .. }
+            | Self::SugarFrenzy { multiplier }
+            | Self::Loan { multiplier, .. }
+            | Self::LoanInterest { multiplier, .. } => Some(multiplier),
+            Self::BuildingDebuff { divisor, .. } => Some(1. / divisor),
+            _ => None,
         }
     }
 
-    fn encode(value: &Vec<GameBuff>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for v in value {
-            format::Standard::encode(&v.effect_id, f)?;
-            write!(f, ",")?;
-            format::Standard::encode(&v.maximum_time, f)?;
-            write!(f, ",")?;
-            format::Standard::encode(&v.time_remaining, f)?;
-            if v.argument1.is_some() || v.argument2.is_some() || v.argument3.is_some() {
-                write!(f, ",")?;
-                format::NoneAsEmpty::<format::Standard>::encode(&v.argument1, f)?;
-            }
-            if v.argument2.is_some() || v.argument3.is_some() {
-                write!(f, ",")?;
-                format::NoneAsEmpty::<format::Standard>::encode(&v.argument2, f)?;
-            }
-            if v.argument3.is_some() {
-                write!(f, ",")?;
-                format::NoneAsEmpty::<format::Standard>::encode(&v.argument3, f)?;
-            }
-            write!(f, ";")?;
+    /// The multiplier applied to the cookies per click, if any.
+    pub fn click_multiplier(&self) -> Option<f64> {
+        match *self {
+            Self::Dragonflight { multiplier }
+            | Self::ClickFrenzy { multiplier }
+            | Self::Devastation { multiplier } => Some(multiplier),
+            _ => None,
         }
-        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Buff, GameBuff};
+    use crate::format::{self, Format};
+    use crate::Building;
+
+    type Buffs = format::Seq<format::Standard, ';', 1, true>;
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_buff() {
+        let save = crate::decode(include_str!("samples/01.txt").trim()).unwrap();
+        assert_eq!(
+            save.game_buffs[0].buff(),
+            Buff::BuildingSpecial {
+                multiplier: 55.2,
+                building: Building::AntimatterCondenser,
+            },
+        );
+
+        let value = "6,154,98,777;7,130,45,1111;";
+        format::check_inverse::<'_, '_, Buffs, Vec<GameBuff>>(value).unwrap();
+        let buffs: Vec<GameBuff> = Buffs::decode(value).unwrap();
+        assert_eq!(buffs[0].buff(), Buff::ClickFrenzy { multiplier: 777. });
+        assert_eq!(buffs[1].buff(), Buff::Dragonflight { multiplier: 1111. });
+
+        let buffs: Vec<GameBuff> = Buffs::decode("27,60,60,2;9,60,60,7;").unwrap();
+        assert_eq!(buffs[0].buff(), Buff::Unknown(27));
+        assert_eq!(buffs[1].buff(), Buff::Unknown(9));
     }
 }
@@ -1,7 +1,9 @@
+mod plant;
+
 use crate::error::Error;
 use crate::format;
 use chrono::{DateTime, Utc};
-use itertools::Itertools;
+pub use plant::{Plant, Stage};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt;
@@ -16,30 +18,73 @@ pub struct Garden {
     pub total_harvests: u64,
     pub unlocked_seeds: Vec<bool>,
     pub farm_grid_data: Vec<Option<FarmGridData>>,
-    pub todo0: String,
-    pub todo1: String,
-    pub todo2: String,
+    pub on_minigame: bool,
+    /// The number of times the garden was sacrificed for sugar lumps.
+    pub convert_times: u64,
+    pub time_of_next_freeze: DateTime<Utc>,
+    pub extra: format::Extra,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, format::Format)]
+#[format(split = ':')]
 pub struct FarmGridData {
     pub id: usize,
     pub age: u64,
 }
 
+impl Garden {
+    pub const WIDTH: usize = 6;
+    pub const HEIGHT: usize = 6;
+
+    /// Iterates over the planted tiles as `(x, y, plant, age, stage)`.
+    pub fn tiles(&self) -> impl Iterator<Item = (usize, usize, Plant, u64, Stage)> + '_ {
+        self.farm_grid_data.iter().enumerate().filter_map(|(i, v)| {
+            let v = v.as_ref()?;
+            let plant = v.plant()?;
+            Some((
+                i % Self::WIDTH,
+                i / Self::WIDTH,
+                plant,
+                v.age,
+                plant.stage(v.age),
+            ))
+        })
+    }
+
+    pub fn unlocked_plants(&self) -> impl Iterator<Item = Plant> + '_ {
+        self.unlocked_seeds
+            .iter()
+            .enumerate()
+            .filter(|(_, unlocked)| **unlocked)
+            .filter_map(|(id, _)| Plant::from_id(id))
+    }
+}
+
+impl FarmGridData {
+    /// `id` is offset by one, as `0` denotes an empty tile.
+    pub fn plant(&self) -> Option<Plant> {
+        Plant::from_id(self.id.checked_sub(1)?)
+    }
+
+    pub fn stage(&self) -> Option<Stage> {
+        Some(self.plant()?.stage(self.age))
+    }
+}
+
 #[derive(format::Format)]
 #[format(split = ' ')]
 struct Format<'a> {
-    inner: Inner<'a>,
-    #[format(with = Custom)]
+    #[format(flatten)]
+    inner: Inner,
+    #[format(seq)]
     unlocked_seeds: Cow<'a, [bool]>,
-    #[format(with = Custom)]
+    #[format(seq(split = ':', width = 2, trailing = true), with = Tile)]
     farm_grid_data: Cow<'a, [Option<FarmGridData>]>,
 }
 
 #[derive(format::Format)]
 #[format(split = ':', trailing = true)]
-struct Inner<'a> {
+struct Inner {
     #[format(with = format::Timestamp)]
     time_of_next_tick: DateTime<Utc>,
     soil_type: usize,
@@ -48,62 +93,32 @@ struct Inner<'a> {
     frozen_garden: bool,
     harvests_this_ascension: u64,
     total_harvests: u64,
-    todo0: Cow<'a, str>,
-    todo1: Cow<'a, str>,
-    todo2: Cow<'a, str>,
+    on_minigame: bool,
+    convert_times: u64,
+    #[format(with = format::Timestamp)]
+    time_of_next_freeze: DateTime<Utc>,
+    #[format(extra)]
+    extra: format::Extra,
 }
 
-struct Custom;
-
-impl<'a> format::Format<'_, Cow<'a, [bool]>> for Custom {
-    #[tracing::instrument(err)]
-    fn decode(value: &str) -> Result<Cow<'a, [bool]>, Error> {
-        format::chars(value).map(format::Standard::decode).collect()
-    }
-
-    fn encode(value: &Cow<'_, [bool]>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for v in value.as_ref() {
-            format::Standard::encode(v, f)?;
-        }
-        Ok(())
-    }
-}
+/// A farm grid tile, where an `id` of `0` is empty.
+struct Tile;
 
-impl<'a> format::Format<'_, Cow<'a, [Option<FarmGridData>]>> for Custom {
-    #[tracing::instrument(err)]
-    fn decode(value: &str) -> Result<Cow<'a, [Option<FarmGridData>]>, Error> {
-        value
-            .split(':')
-            .tuples()
-            .map(|(id, age)| {
-                let id = format::Standard::decode(id)?;
-                let age = format::Standard::decode(age)?;
-                if id == 0 {
-                    Ok(None)
-                } else {
-                    Ok(Some(FarmGridData { id, age }))
-                }
-            })
-            .collect()
+impl format::Format<'_, Option<FarmGridData>> for Tile {
+    fn decode(value: &str) -> Result<Option<FarmGridData>, Error> {
+        let value: FarmGridData = format::Standard::decode(value)?;
+        Ok(Some(value).filter(|v| v.id != 0))
     }
 
-    fn encode(value: &Cow<'a, [Option<FarmGridData>]>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for v in value.as_ref() {
-            if let Some(FarmGridData { id, age }) = v {
-                format::Standard::encode(id, f)?;
-                write!(f, ":")?;
-                format::Standard::encode(age, f)?;
-            } else {
-                write!(f, "0:0")?;
-            }
-            write!(f, ":")?;
+    fn encode(value: &Option<FarmGridData>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match value {
+            Some(value) => format::Standard::encode(value, f),
+            None => write!(f, "0:0"),
         }
-        Ok(())
     }
 }
 
 impl format::Format<'_, Garden> for format::Standard {
-    #[tracing::instrument(err)]
     fn decode(value: &str) -> Result<Garden, Error> {
         let Format {
             inner:
@@ -114,9 +129,10 @@ impl format::Format<'_, Garden> for format::Standard {
                     frozen_garden,
                     harvests_this_ascension,
                     total_harvests,
-                    todo0,
-                    todo1,
-                    todo2,
+                    on_minigame,
+                    convert_times,
+                    time_of_next_freeze,
+                    extra,
                 },
             unlocked_seeds,
             farm_grid_data,
@@ -128,9 +144,10 @@ impl format::Format<'_, Garden> for format::Standard {
             frozen_garden,
             harvests_this_ascension,
             total_harvests,
-            todo0: todo0.into(),
-            todo1: todo1.into(),
-            todo2: todo2.into(),
+            on_minigame,
+            convert_times,
+            time_of_next_freeze,
+            extra,
             unlocked_seeds: unlocked_seeds.into(),
             farm_grid_data: farm_grid_data.into(),
         })
@@ -144,9 +161,10 @@ impl format::Format<'_, Garden> for format::Standard {
             frozen_garden,
             harvests_this_ascension,
             total_harvests,
-            ref todo0,
-            ref todo1,
-            ref todo2,
+            on_minigame,
+            convert_times,
+            time_of_next_freeze,
+            ref extra,
             ref unlocked_seeds,
             ref farm_grid_data,
         } = *value;
@@ -159,9 +177,10 @@ impl format::Format<'_, Garden> for format::Standard {
                     frozen_garden,
                     harvests_this_ascension,
                     total_harvests,
-                    todo0: todo0.into(),
-                    todo1: todo1.into(),
-                    todo2: todo2.into(),
+                    on_minigame,
+                    convert_times,
+                    time_of_next_freeze,
+                    extra: extra.clone(),
                 },
                 unlocked_seeds: unlocked_seeds.into(),
                 farm_grid_data: farm_grid_data.into(),
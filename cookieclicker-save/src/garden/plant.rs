@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+// https://cookieclicker.fandom.com/wiki/Garden#Plants
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Deserialize,
+    Serialize,
+    strum::FromRepr,
+    strum::VariantArray,
+)]
+#[repr(usize)]
+pub enum Plant {
+    BakerWheat,
+    Thumbcorn,
+    Cronerice,
+    Gildmillet,
+    OrdinaryClover,
+    GoldenClover,
+    Shimmerlily,
+    Elderwort,
+    Bakeberry,
+    Chocoroot,
+    WhiteChocoroot,
+    WhiteMildew,
+    BrownMold,
+    Meddleweed,
+    Whiskerbloom,
+    Chimerose,
+    Nursetulip,
+    Drowsyfern,
+    Wardlichen,
+    Keenmoss,
+    Queenbeet,
+    JuicyQueenbeet,
+    Duketater,
+    Crumbspore,
+    Doughshroom,
+    Glovemorel,
+    Cheapcap,
+    FoolsBolete,
+    Wrinklegill,
+    GreenRot,
+    Shriekbulb,
+    Tidygrass,
+    Everdaisy,
+    Ichorpuff,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum Stage {
+    Bud,
+    Sprout,
+    Bloom,
+    Mature,
+}
+
+struct Info {
+    name: &'static str,
+    mature: u64,
+    age_tick: f64,
+    age_tick_random: f64,
+    immortal: bool,
+    weed: bool,
+    fungus: bool,
+}
+
+impl Plant {
+    pub fn id(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_id(id: usize) -> Option<Self> {
+        Self::from_repr(id)
+    }
+
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    /// The age (out of 100) at which the plant becomes mature.
+    pub fn mature(self) -> u64 {
+        self.info().mature
+    }
+
+    /// The base aging per tick, as `(fixed, random)`; each tick adds `fixed + random * rand()`.
+    pub fn aging(self) -> (f64, f64) {
+        let info = self.info();
+        (info.age_tick, info.age_tick_random)
+    }
+
+    /// The average number of ticks until the plant dies, or `None` if it is immortal.
+    pub fn lifespan(self) -> Option<f64> {
+        let info = self.info();
+        if info.immortal {
+            None
+        } else {
+            Some(100. / (info.age_tick + info.age_tick_random / 2.))
+        }
+    }
+
+    pub fn is_immortal(self) -> bool {
+        self.info().immortal
+    }
+
+    pub fn is_weed(self) -> bool {
+        self.info().weed
+    }
+
+    pub fn is_fungus(self) -> bool {
+        self.info().fungus
+    }
+
+    pub fn stage(self, age: u64) -> Stage {
+        let mature = self.mature() as f64;
+        let age = age as f64;
+        if age >= mature {
+            Stage::Mature
+        } else if age >= mature * 0.666 {
+            Stage::Bloom
+        } else if age >= mature * 0.333 {
+            Stage::Sprout
+        } else {
+            Stage::Bud
+        }
+    }
+
+    fn info(self) -> Info {
+        macro_rules! info {
+            ($name:literal, $mature:literal, $age_tick:literal, $age_tick_random:literal $(, $flag:ident)*) => {{
+                #[allow(unused_mut)]
+                let mut info = Info {
+                    name: $name,
+                    mature: $mature,
+                    age_tick: $age_tick,
+                    age_tick_random: $age_tick_random,
+                    immortal: false,
+                    weed: false,
+                    fungus: false,
+                };
+                $(info.$flag = true;)*
+                info
+            }};
+        }
+
+        match self {
+            Self::BakerWheat => info!("Baker's wheat", 35, 7., 2.),
+            Self::Thumbcorn => info!("Thumbcorn", 20, 6., 2.),
+            Self::Cronerice => info!("Cronerice", 55, 0.4, 0.7),
+            Self::Gildmillet => info!("Gildmillet", 40, 5., 1.5),
+            Self::OrdinaryClover => info!("Ordinary clover", 35, 1., 1.5),
+            Self::GoldenClover => info!("Golden clover", 50, 4., 12.),
+            Self::Shimmerlily => info!("Shimmerlily", 70, 5., 6.),
+            Self::Elderwort => info!("Elderwort", 90, 0.3, 0.5, immortal),
+            Self::Bakeberry => info!("Bakeberry", 80, 1., 1.),
+            Self::Chocoroot => info!("Chocoroot", 25, 4., 0.),
+            Self::WhiteChocoroot => info!("White chocoroot", 25, 4., 0.),
+            Self::WhiteMildew => info!("White mildew", 70, 8., 12., fungus),
+            Self::BrownMold => info!("Brown mold", 70, 8., 12., fungus),
+            Self::Meddleweed => info!("Meddleweed", 50, 10., 2., weed),
+            Self::Whiskerbloom => info!("Whiskerbloom", 60, 2., 0.1),
+            Self::Chimerose => info!("Chimerose", 30, 1., 1.5),
+            Self::Nursetulip => info!("Nursetulip", 60, 0.5, 2.),
+            Self::Drowsyfern => info!("Drowsyfern", 30, 0.05, 0.1),
+            Self::Wardlichen => info!("Wardlichen", 65, 5., 4.),
+            Self::Keenmoss => info!("Keenmoss", 65, 4., 5.),
+            Self::Queenbeet => info!("Queenbeet", 80, 1., 0.4),
+            Self::JuicyQueenbeet => info!("Juicy queenbeet", 100, 0.04, 0.1),
+            Self::Duketater => info!("Duketater", 95, 0.4, 0.1),
+            Self::Crumbspore => info!("Crumbspore", 65, 3., 3., fungus),
+            Self::Doughshroom => info!("Doughshroom", 85, 1., 2., fungus),
+            Self::Glovemorel => info!("Glovemorel", 80, 3., 18., fungus),
+            Self::Cheapcap => info!("Cheapcap", 40, 6., 16., fungus),
+            Self::FoolsBolete => info!("Fool's bolete", 75, 6., 16., fungus),
+            Self::Wrinklegill => info!("Wrinklegill", 65, 1., 3., fungus),
+            Self::GreenRot => info!("Green rot", 60, 12., 13., fungus),
+            Self::Shriekbulb => info!("Shriekbulb", 60, 3., 1.),
+            Self::Tidygrass => info!("Tidygrass", 40, 0.5, 0.),
+            Self::Everdaisy => info!("Everdaisy", 75, 0.3, 0., immortal),
+            Self::Ichorpuff => info!("Ichorpuff", 35, 1., 1.5),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::format;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
+#[format(split = ' ')]
+pub struct Grimoire {
+    #[serde(with = "crate::float")]
+    pub magic: f64,
+    pub spells_cast: u64,
+    pub total_spells_cast: u64,
+    pub on_minigame: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, strum::VariantArray)]
+pub enum Spell {
+    ConjureBakedGoods,
+    ForceTheHandOfFate,
+    StretchTime,
+    SpontaneousEdifice,
+    HagglersCharm,
+    SummonCraftyPixies,
+    GamblersFeverDream,
+    ResurrectAbomination,
+    DiminishIneptitude,
+}
+
+impl Grimoire {
+    /// https://cookieclicker.fandom.com/wiki/Grimoire#Magic
+    pub fn max_magic(wizard_towers: u64, level: usize) -> f64 {
+        let towers = wizard_towers.max(1) as f64;
+        let level = level.max(1) as f64;
+        (4. + towers.powf(0.6) + ((towers + (level - 1.) * 10.) / 15. + 1.).ln() * 15.).floor()
+    }
+}
+
+impl Spell {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ConjureBakedGoods => "Conjure Baked Goods",
+            Self::ForceTheHandOfFate => "Force the Hand of Fate",
+            Self::StretchTime => "Stretch Time",
+            Self::SpontaneousEdifice => "Spontaneous Edifice",
+            Self::HagglersCharm => "Haggler's Charm",
+            Self::SummonCraftyPixies => "Summon Crafty Pixies",
+            Self::GamblersFeverDream => "Gambler's Fever Dream",
+            Self::ResurrectAbomination => "Resurrect Abomination",
+            Self::DiminishIneptitude => "Diminish Ineptitude",
+        }
+    }
+
+    /// Base cost, without the modifiers from upgrades and spirits.
+    pub fn cost(self, max_magic: f64) -> f64 {
+        let (min, percent) = match self {
+            Self::ConjureBakedGoods => (2., 0.4),
+            Self::ForceTheHandOfFate => (10., 0.6),
+            Self::StretchTime => (8., 0.2),
+            Self::SpontaneousEdifice => (20., 0.75),
+            Self::HagglersCharm => (10., 0.1),
+            Self::SummonCraftyPixies => (10., 0.2),
+            Self::GamblersFeverDream => (3., 0.05),
+            Self::ResurrectAbomination => (20., 0.1),
+            Self::DiminishIneptitude => (5., 0.2),
+        };
+        (min + max_magic * percent).floor()
+    }
+}
@@ -1,31 +1,107 @@
 // https://cookieclicker.fandom.com/wiki/Save
 
+extern crate self as cookieclicker_save;
+
 mod achievements;
+mod building;
+mod diff;
 mod error;
 mod escape;
-mod format;
+mod float;
+pub mod format;
 mod game_buffs;
 mod garden;
+mod grimoire;
+mod miscellaneous;
+mod pantheon;
+mod patch;
+mod save_ref;
+mod stock_market;
 mod upgrades;
+mod validate;
+mod version;
 
+pub use achievements::{Achievement, AchievementPool, Achievements};
+pub use building::Building;
 use chrono::{DateTime, Utc};
-pub use error::Error;
+pub use diff::{diff, Change};
+pub use error::{Diagnostic, Error};
+pub use escape::Form;
+pub use format::Extra;
 use format::Format as _;
-pub use game_buffs::GameBuff;
-pub use garden::{FarmGridData, Garden};
+pub use game_buffs::{Buff, GameBuff};
+pub use garden::{FarmGridData, Garden, Plant, Stage};
+pub use grimoire::{Grimoire, Spell};
+pub use miscellaneous::{
+    BackgroundType, DragonAura, DragonLevel, Grandmapocalypse, MilkType, Season, SugarLumpType,
+};
+pub use pantheon::{Pantheon, Slot, Spirit};
+pub use patch::Operation;
+pub use save_ref::{RunDetailsRef, SaveRef};
 use serde::{Deserialize, Serialize};
-pub use upgrades::Upgrade;
+pub use stock_market::{GoodData, GoodMode, Loan, StockMarket};
+pub use upgrades::{Upgrade, UpgradeId, UpgradePool, Upgrades};
+pub use validate::{Severity, Violation};
+pub use version::GameVersion;
 
-#[tracing::instrument(err)]
+#[tracing::instrument(skip_all, err)]
 pub fn decode(value: &str) -> Result<Save, Error> {
     format::Standard::decode(&escape::decode(value)?)
 }
 
-#[tracing::instrument]
+/// Like [`decode`], also returning the form of the save code, which [`encode_as`] reproduces.
+#[tracing::instrument(skip_all, err)]
+pub fn decode_with_form(value: &str) -> Result<(Save, Form), Error> {
+    let (value, form) = escape::decode_with_form(value)?;
+    Ok((format::Standard::decode(&value)?, form))
+}
+
+/// Decodes a save code into the save text, which [`SaveRef`] borrows from.
+#[tracing::instrument(skip_all, err)]
+pub fn decode_text(value: &str) -> Result<String, Error> {
+    escape::decode(value)
+}
+
+/// Like [`decode`], but replaces the fields that fail to decode with defaults where possible,
+/// reporting each of them.
+#[tracing::instrument(skip_all, err)]
+pub fn decode_lenient(value: &str) -> Result<(Save, Vec<Diagnostic>), Error> {
+    let value = escape::decode(value)?;
+    let (save, diagnostics) = format::collect(|| format::Standard::decode(&value));
+    Ok((
+        save?,
+        diagnostics.into_iter().map(Diagnostic::from).collect(),
+    ))
+}
+
+#[tracing::instrument(skip_all)]
 pub fn encode(value: &Save) -> String {
     escape::encode(&format::Standard::display(value).to_string())
 }
 
+/// Like [`encode`], but in the form `form` rather than the game's.
+#[tracing::instrument(skip_all)]
+pub fn encode_as(value: &Save, form: &Form) -> String {
+    escape::encode_as(&format::Standard::display(value).to_string(), form)
+}
+
+/// Applies `operations` to the save `value`, returning the new save code in the same form.
+#[tracing::instrument(skip_all, err)]
+pub fn patch(value: &str, operations: &[Operation]) -> Result<String, Error> {
+    let (save, form) = decode_with_form(value)?;
+    Ok(encode_as(&save.apply(operations)?, &form))
+}
+
+/// Encodes `value` with the layout of `version`, leaving out the fields it did not have yet
+/// and fitting the upgrade and achievement lists to it where their lengths are known.
+/// Minigame data is kept as it is.
+#[tracing::instrument(skip_all)]
+pub fn encode_for(value: &Save, version: GameVersion) -> String {
+    let mut value = value.clone();
+    version::retarget(&mut value, version);
+    encode(&value)
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
 #[format(split = '|', trailing = true)]
 pub struct Save {
@@ -35,18 +111,41 @@ pub struct Save {
     pub preferences: Preferences,
     pub miscellaneous_game_data: MiscellaneousGameData,
     pub building_data: BuildingData,
-    #[format(with = upgrades::Custom)]
+    #[format(seq(width = 2))]
     pub upgrades: Vec<Upgrade>,
-    #[format(with = achievements::Custom)]
+    #[format(seq)]
     pub achievements: Vec<bool>,
-    #[format(with = game_buffs::Custom)]
+    #[format(seq(split = ';', trailing = true))]
     pub game_buffs: Vec<GameBuff>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
-#[format(split = ';')]
-pub struct GameVersion {
-    pub game_version: String,
+impl Save {
+    pub fn achievements(&self) -> Achievements<'_> {
+        Achievements::new(&self.achievements)
+    }
+
+    pub fn upgrades(&self) -> Upgrades<'_> {
+        Upgrades::new(&self.upgrades)
+    }
+
+    pub fn loans(&self) -> impl Iterator<Item = Loan> + '_ {
+        self.game_buffs.iter().filter_map(Loan::from_game_buff)
+    }
+
+    /// Looks up the serialized value at `path`, in the syntax of [`Operation::path`].
+    pub fn get(&self, path: &str) -> Result<serde_json::Value, Error> {
+        patch::get(self, path)
+    }
+
+    /// Returns a copy of the save with `operations` applied, see [`patch`].
+    pub fn apply(&self, operations: &[Operation]) -> Result<Save, Error> {
+        patch::apply(self, operations)
+    }
+
+    /// Checks the save for inconsistencies that decoding alone does not catch.
+    pub fn validate(&self) -> Vec<Violation> {
+        validate::validate(self)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
@@ -61,9 +160,11 @@ pub struct RunDetails {
     pub bakery_name: String,
     pub seed: String,
     pub you_appearance: YouAppearance,
+    #[format(extra)]
+    pub extra: format::Extra,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, format::Format)]
 #[format(split = ',')]
 pub struct YouAppearance {
     pub hair: usize,
@@ -104,21 +205,27 @@ pub struct Preferences {
     pub fullscreen: bool,
     pub screan_reader: bool,
     pub todo0: bool,
+    #[format(extra)]
+    pub extra: format::Extra,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
 #[format(split = ';', trailing = true)]
 pub struct MiscellaneousGameData {
+    #[serde(with = "crate::float")]
     pub cookies_in_bank: f64,
+    #[serde(with = "crate::float")]
     pub cookies_baked: f64,
     pub cookie_clicks: u64,
     pub total_golden_cookie_clicks: u64,
+    #[serde(with = "crate::float")]
     pub hand_made_cookies: f64,
     pub total_golden_cookies_missed: u64,
-    pub background_type: usize,
-    pub milk_type: usize,
+    pub background_type: BackgroundType,
+    pub milk_type: MilkType,
+    #[serde(with = "crate::float")]
     pub cookies_forfeited_by_ascending: f64,
-    pub grandmapocalypse_stage: usize,
+    pub grandmapocalypse_stage: Grandmapocalypse,
     pub elder_pledges_made: u64,
     pub time_left_in_elder_pledge: u64,
     pub currently_researching: usize,
@@ -126,6 +233,7 @@ pub struct MiscellaneousGameData {
     pub time_left_in_research: Option<u64>,
     pub ascensions: u64,
     pub golden_cookie_clicks: u64,
+    #[serde(with = "crate::float")]
     pub cookies_sucked_by_wrinklers: f64,
     pub wrinklers_popped: u64,
     pub santa_level: usize,
@@ -134,13 +242,18 @@ pub struct MiscellaneousGameData {
     pub time_left_in_season: Option<u64>,
     pub season_switcher_uses: u64,
     #[format(with = format::NoneAsEmpty)]
-    pub current_season: Option<String>,
+    pub current_season: Option<Season>,
+    #[serde(with = "crate::float")]
     pub cookies_contained_in_wrinklers: f64,
     pub number_of_wrinklers: u64,
+    #[serde(with = "crate::float")]
     pub prestive_level: f64,
+    #[serde(with = "crate::float")]
     pub heavenly_chips: f64,
+    #[serde(with = "crate::float")]
     pub heavenly_chips_spent: f64,
-    heavenly_cookies: String,
+    #[serde(with = "crate::float")]
+    pub heavenly_cookies: f64,
     pub ascension_mode: bool,
     #[format(with = format::NoneAsNegative)]
     pub permanent_upgrade_i: Option<usize>,
@@ -152,12 +265,13 @@ pub struct MiscellaneousGameData {
     pub permanent_upgrade_iv: Option<usize>,
     #[format(with = format::NoneAsNegative)]
     pub permanent_upgrade_v: Option<usize>,
-    pub dragon_level: usize,
-    pub dragon_aura: usize,
-    pub dragon_aura_2: usize,
+    pub dragon_level: DragonLevel,
+    pub dragon_aura: DragonAura,
+    pub dragon_aura_2: DragonAura,
     pub golden_cookie_chime_type: usize,
     pub volume: u64,
     pub number_of_shiny_wrinklers: u64,
+    #[serde(with = "crate::float")]
     pub cookies_in_shiny_wrinklers: f64,
     #[format(with = format::NoneAsNegative)]
     pub sugar_lumps: Option<u64>,
@@ -167,15 +281,20 @@ pub struct MiscellaneousGameData {
     pub time_of_start_of_sugar_lump: DateTime<Utc>,
     #[format(with = format::NoneAsZero<format::Timestamp>)]
     pub time_of_last_minigame_refill: Option<DateTime<Utc>>,
-    pub sugar_lump_type: usize,
-    pub upgrades_in_vault: String,
+    pub sugar_lump_type: SugarLumpType,
+    #[format(seq(split = ','))]
+    pub upgrades_in_vault: Vec<usize>,
     pub heralds: u64,
     pub todo0: String,
     pub todo1: String,
     pub todo2: String,
     pub music_volume: u64,
+    #[serde(with = "crate::float")]
     pub cookies_sent: f64,
+    #[serde(with = "crate::float")]
     pub cookies_received: f64,
+    #[format(extra)]
+    pub extra: format::Extra,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
@@ -186,9 +305,9 @@ pub struct BuildingData {
     pub farms: BuildingDataEntry<Garden>,
     pub mines: BuildingDataEntry,
     pub factories: BuildingDataEntry,
-    pub banks: BuildingDataEntry<String>,
-    pub temples: BuildingDataEntry<String>,
-    pub wizard_towers: BuildingDataEntry<String>,
+    pub banks: BuildingDataEntry<StockMarket>,
+    pub temples: BuildingDataEntry<Pantheon>,
+    pub wizard_towers: BuildingDataEntry<Grimoire>,
     pub shipments: BuildingDataEntry,
     pub alchemy_labs: BuildingDataEntry,
     pub portals: BuildingDataEntry,
@@ -201,6 +320,8 @@ pub struct BuildingData {
     pub idleverses: BuildingDataEntry,
     pub cortex_bakers: BuildingDataEntry,
     pub yous: BuildingDataEntry,
+    #[format(extra)]
+    pub extra: format::Extra,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
@@ -208,6 +329,7 @@ pub struct BuildingData {
 pub struct BuildingDataEntry<M = ()> {
     pub amount_owned: u64,
     pub amount_bought: u64,
+    #[serde(with = "crate::float")]
     pub cookies_produced: f64,
     pub level: usize,
     #[format(with = format::NoneAsEmpty)]
@@ -216,9 +338,71 @@ pub struct BuildingDataEntry<M = ()> {
     pub highest_amount: u64,
 }
 
+impl BuildingData {
+    /// Every entry without its minigame data, in building order.
+    pub fn entries(&self) -> [(Building, BuildingDataEntry); 20] {
+        macro_rules! entries {
+            ($($field:ident => $building:ident,)*) => {
+                [$((Building::$building, self.$field.without_minigame()),)*]
+            };
+        }
+        entries! {
+            cursors => Cursor,
+            grandmas => Grandma,
+            farms => Farm,
+            mines => Mine,
+            factories => Factory,
+            banks => Bank,
+            temples => Temple,
+            wizard_towers => WizardTower,
+            shipments => Shipment,
+            alchemy_labs => AlchemyLab,
+            portals => Portal,
+            time_machines => TimeMachine,
+            antimatter_condensers => AntimatterCondenser,
+            prisms => Prism,
+            chancemakers => Chancemaker,
+            fractal_engines => FractalEngine,
+            javascript_consoles => JavascriptConsole,
+            idleverses => Idleverse,
+            cortex_bakers => CortexBaker,
+            yous => You,
+        }
+    }
+}
+
+impl<M> BuildingDataEntry<M> {
+    pub fn without_minigame(&self) -> BuildingDataEntry {
+        BuildingDataEntry {
+            amount_owned: self.amount_owned,
+            amount_bought: self.amount_bought,
+            cookies_produced: self.cookies_produced,
+            level: self.level,
+            minigame_data: self.minigame_data.as_ref().map(|_| ()),
+            muted: self.muted,
+            highest_amount: self.highest_amount,
+        }
+    }
+}
+
+impl<M> Default for BuildingDataEntry<M> {
+    fn default() -> Self {
+        Self {
+            amount_owned: 0,
+            amount_bought: 0,
+            cookies_produced: 0.,
+            level: 0,
+            minigame_data: None,
+            muted: false,
+            highest_amount: 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{escape, format};
+    use crate::escape;
+    use crate::format::{self, Format};
 
     #[test]
     #[tracing_test::traced_test]
@@ -232,4 +416,162 @@ mod tests {
             format::check_inverse::<'_, '_, format::Standard, super::Save>(&value).unwrap();
         }
     }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_save_synthetic() {
+        // Not a real export: sample 01 with hand-written Bank, Temple and Wizard tower
+        // minigame data spliced in. It only checks that those round-trip through the codecs.
+        let value = include_str!("samples/synthetic/minigames.txt");
+        let value = escape::decode(value.trim()).unwrap();
+        format::check_inverse::<'_, '_, format::Standard, super::Save>(&value).unwrap();
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_leftover() {
+        let error =
+            <format::Standard as Format<super::BuildingDataEntry>>::decode("1,2,3,0,,0,5,99")
+                .unwrap_err();
+        assert_eq!(error.to_string(), r#": trailing data at byte 13 ("99")"#);
+
+        let value = escape::decode(include_str!("samples/00.txt").trim()).unwrap();
+        let value = format!("{value}NEWSECTION|");
+        let error = crate::decode(&escape::encode(&value)).unwrap_err();
+        assert!(matches!(error.kind(), super::Error::TrailingData));
+        let (_, diagnostics) = crate::decode_lenient(&escape::encode(&value)).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].raw, "NEWSECTION");
+        assert_eq!(diagnostics[0].offset, value.len() - "NEWSECTION|".len());
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_extra() {
+        let value: super::Preferences =
+            format::Standard::decode("11110111110111111100100000001").unwrap();
+        assert_eq!(value.extra.unknown.as_deref(), Some("01"));
+        let value: super::Preferences =
+            format::Standard::decode("1111011111011111110010000").unwrap();
+        assert_eq!(value.extra.defaulted, ["screan_reader", "todo0"]);
+        assert!(!value.screan_reader);
+        format::check_inverse::<'_, '_, format::Standard, super::Preferences>(
+            "11110111110111111100100000001",
+        )
+        .unwrap();
+        format::check_inverse::<'_, '_, format::Standard, super::Preferences>(
+            "1111011111011111110010000",
+        )
+        .unwrap();
+        let mut value: super::Preferences =
+            format::Standard::decode("1111011111011111110010000").unwrap();
+        value.todo0 = true;
+        assert_eq!(
+            format::Standard::display(&value).to_string(),
+            "111101111101111111001000001"
+        );
+
+        let value = escape::decode(include_str!("samples/00.txt").trim()).unwrap();
+        let value = value.split('|').nth(4).unwrap();
+        let longer = format!("{value}42;");
+        let decoded: super::MiscellaneousGameData = format::Standard::decode(&longer).unwrap();
+        assert_eq!(decoded.extra.unknown.as_deref(), Some("42"));
+        format::check_inverse::<'_, '_, format::Standard, super::MiscellaneousGameData>(&longer)
+            .unwrap();
+        let shorter = value
+            .split(';')
+            .take(52)
+            .map(|v| format!("{v};"))
+            .collect::<String>();
+        let decoded: super::MiscellaneousGameData = format::Standard::decode(&shorter).unwrap();
+        assert_eq!(
+            decoded.extra.defaulted,
+            ["music_volume", "cookies_sent", "cookies_received"]
+        );
+        format::check_inverse::<'_, '_, format::Standard, super::MiscellaneousGameData>(&shorter)
+            .unwrap();
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_encode_for() {
+        let save = crate::decode(include_str!("samples/00.txt").trim()).unwrap();
+        let old = crate::encode_for(&save, "2.031".parse().unwrap());
+        let decoded = crate::decode(&old).unwrap();
+        assert_eq!(decoded.game_version, "2.031".parse().unwrap());
+        assert_eq!(
+            decoded.building_data.extra.defaulted,
+            ["cortex_bakers", "yous"]
+        );
+        assert_eq!(decoded.run_details.extra.defaulted, ["you_appearance"]);
+        assert_eq!(decoded.building_data.yous.amount_owned, 0);
+        assert_eq!(
+            decoded.building_data.idleverses.amount_owned,
+            save.building_data.idleverses.amount_owned
+        );
+        let value = escape::decode(&old).unwrap();
+        format::check_inverse::<'_, '_, format::Standard, super::Save>(&value).unwrap();
+
+        let new = crate::encode_for(&decoded, super::GameVersion::LATEST);
+        assert!(crate::decode(&new)
+            .unwrap()
+            .building_data
+            .extra
+            .defaulted
+            .is_empty());
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_error_path() {
+        let value = escape::decode(include_str!("samples/00.txt").trim()).unwrap();
+        let grid = value.split('|').nth(5).unwrap().split(';').nth(2).unwrap();
+        let grid = grid.split(',').nth(4).unwrap().split(' ').nth(2).unwrap();
+        let corrupted = format!("{}x{}", &grid[..48], &grid[49..]);
+        let value = value.replacen(grid, &corrupted, 1);
+
+        let error = format::Standard::decode(&value)
+            .map(|_: super::Save| ())
+            .unwrap_err();
+        let super::Error::Field {
+            path, raw, offset, ..
+        } = &error
+        else {
+            panic!("{error:?}");
+        };
+        assert_eq!(
+            path,
+            "building_data.farms.minigame_data.farm_grid_data[12].id"
+        );
+        assert_eq!(raw, "x");
+        assert_eq!(*offset, value.find(&corrupted).unwrap() + 48);
+        assert!(matches!(error.kind(), super::Error::Int(_)));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_decode_lenient() {
+        let value = escape::decode(include_str!("samples/00.txt").trim()).unwrap();
+        let cursors = value.split('|').nth(5).unwrap().split(';').next().unwrap();
+        let (owned, rest) = cursors.split_once(',').unwrap();
+        let value = value.replacen(cursors, &format!("{owned}x,{rest}"), 1);
+        let (save, diagnostics) = crate::decode_lenient(&escape::encode(&value)).unwrap();
+
+        let paths = diagnostics
+            .iter()
+            .map(|v| v.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["building_data.cursors.amount_owned"]);
+        assert_eq!(diagnostics[0].raw, format!("{owned}x"));
+        assert_eq!(save.building_data.cursors.amount_owned, 0);
+        assert!(crate::decode(&escape::encode(&value)).is_err());
+
+        // The layout depends on the version, so a bad one is not replaced.
+        let value = value.replacen("2.052|", "2.05x|", 1);
+        let error = crate::decode_lenient(&escape::encode(&value)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"game_version: invalid data at byte 0 ("2.05x")"#
+        );
+    }
 }
@@ -0,0 +1,336 @@
+use crate::error::Error;
+use crate::format;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+macro_rules! code_enum {
+    (@code String, $value:literal) => {
+        String::from($value)
+    };
+    (@code $code:ident, $value:literal) => {
+        $value
+    };
+    (
+        $(#[$meta:meta])*
+        $name:ident($code:ident), $(#[$other_meta:meta])* $other:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal, $display:literal;)*
+        }
+    ) => {
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, format::Format)]
+        $(#[$meta])*
+        pub enum $name {
+            $($(#[$variant_meta])* #[format(code = $value)] $variant,)*
+            #[format(other)]
+            $(#[$other_meta])*
+            $other($code),
+        }
+
+        impl $name {
+            pub fn code(&self) -> $code {
+                match self {
+                    $(Self::$variant => code_enum!(@code $code, $value),)*
+                    Self::$other(code) => code.clone(),
+                }
+            }
+
+            pub fn from_code(code: $code) -> Self {
+                $(if code == $value {
+                    return Self::$variant;
+                })*
+                Self::$other(code)
+            }
+
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some($display),)*
+                    Self::$other(_) => None,
+                }
+            }
+        }
+    };
+}
+
+code_enum! {
+    Season(String), #[format(fallback)] Unknown {
+        Christmas = "christmas", "Christmas";
+        Valentines = "valentines", "Valentine's day";
+        BusinessDay = "fools", "Business day";
+        Easter = "easter", "Easter";
+        Halloween = "halloween", "Halloween";
+    }
+}
+
+code_enum! {
+    #[derive(Default)]
+    Grandmapocalypse(usize), Unknown {
+        #[default]
+        #[format(fallback)]
+        Appeased = 0, "Appeased";
+        Awoken = 1, "Awoken";
+        Displeased = 2, "Displeased";
+        Angered = 3, "Angered";
+    }
+}
+
+code_enum! {
+    #[derive(Default)]
+    DragonAura(usize), Unknown {
+        #[default]
+        #[format(fallback)]
+        NoAura = 0, "No aura";
+        BreathOfMilk = 1, "Breath of Milk";
+        DragonCursor = 2, "Dragon Cursor";
+        ElderBattalion = 3, "Elder Battalion";
+        ReaperOfFields = 4, "Reaper of Fields";
+        EarthShatterer = 5, "Earth Shatterer";
+        MasterOfTheArmory = 6, "Master of the Armory";
+        FierceHoarder = 7, "Fierce Hoarder";
+        DragonGod = 8, "Dragon God";
+        ArcaneAura = 9, "Arcane Aura";
+        Dragonflight = 10, "Dragonflight";
+        AncestralMetamorphosis = 11, "Ancestral Metamorphosis";
+        UnholyDominion = 12, "Unholy Dominion";
+        EpochManipulator = 13, "Epoch Manipulator";
+        MindOverMatter = 14, "Mind Over Matter";
+        RadiantAppetite = 15, "Radiant Appetite";
+        DragonsFortune = 16, "Dragon's Fortune";
+        DragonsCurve = 17, "Dragon's Curve";
+        RealityBending = 18, "Reality Bending";
+        DragonOrbs = 19, "Dragon Orbs";
+        SupremeIntellect = 20, "Supreme Intellect";
+        DragonGuts = 21, "Dragon Guts";
+    }
+}
+
+code_enum! {
+    #[derive(Default)]
+    SugarLumpType(usize), Unknown {
+        #[default]
+        #[format(fallback)]
+        Normal = 0, "Normal";
+        Bifurcated = 1, "Bifurcated";
+        Golden = 2, "Golden";
+        Meaty = 3, "Meaty";
+        Caramelized = 4, "Caramelized";
+    }
+}
+
+code_enum! {
+    /// The milk picked with the milk selector, by rank.
+    #[derive(Default)]
+    MilkType(usize), Selected {
+        #[default]
+        #[format(fallback)]
+        Automatic = 0, "Automatic";
+        PlainMilk = 1, "Plain milk";
+        ChocolateMilk = 2, "Chocolate milk";
+        RaspberryMilk = 3, "Raspberry milk";
+        OrangeMilk = 4, "Orange milk";
+        CaramelMilk = 5, "Caramel milk";
+        BananaMilk = 6, "Banana milk";
+        LimeMilk = 7, "Lime milk";
+        BlueberryMilk = 8, "Blueberry milk";
+        StrawberryMilk = 9, "Strawberry milk";
+        VanillaMilk = 10, "Vanilla milk";
+        ZebraMilk = 11, "Zebra milk";
+        CosmicMilk = 12, "Cosmic milk";
+        FlamingMilk = 13, "Flaming milk";
+        SanguineMilk = 14, "Sanguine milk";
+        MidasMilk = 15, "Midas milk";
+        MidnightMilk = 16, "Midnight milk";
+        GreenInfernoMilk = 17, "Green inferno milk";
+        FrostfireMilk = 18, "Frostfire milk";
+        HoneyMilk = 19, "Honey milk";
+        CoffeeMilk = 20, "Coffee milk";
+        TeaMilk = 21, "Tea milk";
+        CoconutMilk = 22, "Coconut milk";
+        CherryMilk = 23, "Cherry milk";
+        SoyMilk = 24, "Soy milk";
+        SpicedMilk = 25, "Spiced milk";
+        MapleMilk = 26, "Maple milk";
+        MintMilk = 27, "Mint milk";
+        LicoriceMilk = 28, "Licorice milk";
+        RoseMilk = 29, "Rose milk";
+        DragonfruitMilk = 30, "Dragonfruit milk";
+        MelonMilk = 31, "Melon milk";
+        BlackcurrantMilk = 32, "Blackcurrant milk";
+        PeachMilk = 33, "Peach milk";
+        HazelnutMilk = 34, "Hazelnut milk";
+    }
+}
+
+code_enum! {
+    /// The background picked with the background selector.
+    #[derive(Default)]
+    BackgroundType(usize), Selected {
+        #[default]
+        #[format(fallback)]
+        Automatic = 0, "Automatic";
+        Blue = 1, "Blue";
+        Red = 2, "Red";
+        White = 3, "White";
+        Black = 4, "Black";
+        Gold = 5, "Gold";
+        Grandmas = 6, "Grandmas";
+        DispleasedGrandmas = 7, "Displeased grandmas";
+        AngeredGrandmas = 8, "Angered grandmas";
+        Money = 9, "Money";
+        Purple = 10, "Purple";
+        Pink = 11, "Pink";
+        Mint = 12, "Mint";
+        Silver = 13, "Silver";
+        BlackAndWhite = 14, "Black & White";
+        Spectrum = 15, "Spectrum";
+        Candy = 16, "Candy";
+        BiscuitStore = 17, "Biscuit store";
+        Chocolate = 18, "Chocolate";
+        DarkChocolate = 19, "Dark chocolate";
+        Painter = 20, "Painter";
+        Snow = 21, "Snow";
+        Sky = 22, "Sky";
+        Night = 23, "Night";
+        Foil = 24, "Foil";
+    }
+}
+
+/// The training stage of Krumblor, as in `Game.dragonLevel`.
+///
+/// Each level past the hatchling trains the next aura in [`DragonAura`] order, which is then
+/// available. Levels the game does not have are kept as `Unknown`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum DragonLevel {
+    #[default]
+    Egg,
+    ChippedEgg,
+    TwiceChippedEgg,
+    ShiveringEgg,
+    Hatchling,
+    /// Trained up to the given aura.
+    Trained(DragonAura),
+    /// Baked the dragon cookie, after training every aura.
+    DragonCookie,
+    /// Trained the secondary aura slot.
+    FullyTrained,
+    Unknown(usize),
+}
+
+impl DragonLevel {
+    const TRAINED: usize = 5;
+    // After the 21 auras up to Dragon Guts.
+    const DRAGON_COOKIE: usize = 26;
+
+    pub fn level(&self) -> usize {
+        match self {
+            Self::Egg => 0,
+            Self::ChippedEgg => 1,
+            Self::TwiceChippedEgg => 2,
+            Self::ShiveringEgg => 3,
+            Self::Hatchling => 4,
+            Self::Trained(aura) => Self::TRAINED - 1 + aura.code(),
+            Self::DragonCookie => Self::DRAGON_COOKIE,
+            Self::FullyTrained => Self::DRAGON_COOKIE + 1,
+            Self::Unknown(level) => *level,
+        }
+    }
+
+    pub fn from_level(level: usize) -> Self {
+        match level {
+            0 => Self::Egg,
+            1 => Self::ChippedEgg,
+            2 => Self::TwiceChippedEgg,
+            3 => Self::ShiveringEgg,
+            4 => Self::Hatchling,
+            level if level < Self::DRAGON_COOKIE => {
+                Self::Trained(DragonAura::from_code(level - (Self::TRAINED - 1)))
+            }
+            level if level == Self::DRAGON_COOKIE => Self::DragonCookie,
+            level if level == Self::DRAGON_COOKIE + 1 => Self::FullyTrained,
+            level => Self::Unknown(level),
+        }
+    }
+}
+
+impl PartialOrd for DragonLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DragonLevel {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.level().cmp(&other.level())
+    }
+}
+
+impl format::Format<'_, DragonLevel> for format::Standard {
+    fn decode(value: &str) -> Result<DragonLevel, Error> {
+        Ok(DragonLevel::from_level(value.parse()?))
+    }
+
+    fn encode(value: &DragonLevel, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", value.level())
+    }
+
+    fn fallback() -> Option<DragonLevel> {
+        Some(DragonLevel::Egg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BackgroundType, DragonAura, DragonLevel, MilkType, Season};
+    use crate::error::Error;
+    use crate::format::{self, Format};
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_code_enum() {
+        let value: DragonAura = format::Standard::decode("15").unwrap();
+        assert_eq!(value, DragonAura::RadiantAppetite);
+        let value: DragonAura = format::Standard::decode("42").unwrap();
+        assert_eq!(value, DragonAura::Unknown(42));
+        for value in ["015", "+15", "042"] {
+            let error = <format::Standard as Format<DragonAura>>::decode(value).unwrap_err();
+            assert!(matches!(error, Error::InvalidData), "{value}");
+        }
+        let value: Season = format::Standard::decode("fools").unwrap();
+        assert_eq!(value, Season::BusinessDay);
+        format::check_inverse::<'_, '_, format::Standard, DragonAura>("42").unwrap();
+        format::check_inverse::<'_, '_, format::Standard, Season>("halloween").unwrap();
+        format::check_inverse::<'_, '_, format::Standard, Season>("cny").unwrap();
+        let value: Option<Season> = format::Standard::fallback();
+        assert_eq!(value, Some(Season::Unknown(String::new())));
+        let value: Option<DragonAura> = format::Standard::fallback();
+        assert_eq!(value, Some(DragonAura::NoAura));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_dragon_level() {
+        for (value, expected) in [
+            ("0", DragonLevel::Egg),
+            ("3", DragonLevel::ShiveringEgg),
+            ("4", DragonLevel::Hatchling),
+            ("14", DragonLevel::Trained(DragonAura::Dragonflight)),
+            ("25", DragonLevel::Trained(DragonAura::DragonGuts)),
+            ("26", DragonLevel::DragonCookie),
+            ("27", DragonLevel::FullyTrained),
+            ("28", DragonLevel::Unknown(28)),
+        ] {
+            let value: DragonLevel = format::Standard::decode(value).unwrap();
+            assert_eq!(value, expected);
+            format::check_inverse::<'_, '_, format::Standard, DragonLevel>(
+                &value.level().to_string(),
+            )
+            .unwrap();
+        }
+        assert!(DragonLevel::Hatchling < DragonLevel::Trained(DragonAura::BreathOfMilk));
+
+        let save = crate::decode(include_str!("samples/01.txt").trim()).unwrap();
+        let misc = save.miscellaneous_game_data;
+        assert_eq!(misc.dragon_level, DragonLevel::FullyTrained);
+        assert_eq!(misc.milk_type, MilkType::TeaMilk);
+        assert_eq!(misc.background_type, BackgroundType::Automatic);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::format;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
+#[format(split = ' ')]
+pub struct Pantheon {
+    #[format(seq(split = '/'), with = format::NoneAsNegative)]
+    pub slots: Vec<Option<Spirit>>,
+    pub swaps: u64,
+    #[format(with = format::Timestamp)]
+    pub time_of_last_swap: DateTime<Utc>,
+    pub on_minigame: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, strum::VariantArray)]
+pub enum Slot {
+    Diamond,
+    Ruby,
+    Jade,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Deserialize,
+    Serialize,
+    strum::FromRepr,
+    strum::VariantArray,
+    format::Format,
+)]
+#[repr(usize)]
+pub enum Spirit {
+    Holobore,
+    Vomitrax,
+    Godzamok,
+    Cyclius,
+    Selebrak,
+    Dotjeiess,
+    Muridal,
+    Jeremy,
+    Mokalsium,
+    Skruuia,
+    Rigidel,
+}
+
+impl Pantheon {
+    pub fn slot(&self, slot: Slot) -> Option<Spirit> {
+        self.slots.get(slot as usize).copied().flatten()
+    }
+
+    pub fn slot_of(&self, spirit: Spirit) -> Option<Slot> {
+        <Slot as strum::VariantArray>::VARIANTS
+            .iter()
+            .copied()
+            .find(|slot| self.slot(*slot) == Some(spirit))
+    }
+}
+
+impl Spirit {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Holobore => "Holobore, Spirit of Asceticism",
+            Self::Vomitrax => "Vomitrax, Spirit of Decadence",
+            Self::Godzamok => "Godzamok, Spirit of Ruin",
+            Self::Cyclius => "Cyclius, Spirit of Ages",
+            Self::Selebrak => "Selebrak, Spirit of Festivities",
+            Self::Dotjeiess => "Dotjeiess, Spirit of Creation",
+            Self::Muridal => "Muridal, Spirit of Labor",
+            Self::Jeremy => "Jeremy, Spirit of Industry",
+            Self::Mokalsium => "Mokalsium, Mother Spirit",
+            Self::Skruuia => "Skruuia, Spirit of Scorn",
+            Self::Rigidel => "Rigidel, Spirit of Order",
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::error::Error;
+use crate::float;
+use crate::Save;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Sets the field at `path`, such as `miscellaneous_game_data.cookies_in_bank` or
+/// `upgrades[123].bought`, to `value`. Paths follow the serialized form of [`Save`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Operation {
+    pub path: String,
+    pub value: Value,
+}
+
+enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+fn parse(path: &str) -> Option<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut indices) = part.find('[').map_or((part, ""), |i| part.split_at(i));
+        if key.is_empty() {
+            return None;
+        }
+        segments.push(Segment::Key(key));
+        while !indices.is_empty() {
+            let (index, rest) = indices.strip_prefix('[')?.split_once(']')?;
+            segments.push(Segment::Index(index.parse().ok()?));
+            indices = rest;
+        }
+    }
+    Some(segments)
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(value) if float::is_text(value) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn set(root: &mut Value, operation: &Operation) -> Result<(), Error> {
+    let invalid_path = || Error::InvalidPath {
+        path: operation.path.clone(),
+    };
+    let segments = parse(&operation.path).ok_or_else(invalid_path)?;
+    let (last, parents) = segments.split_last().ok_or_else(invalid_path)?;
+
+    let mut parent = root;
+    for segment in parents {
+        parent = match segment {
+            Segment::Key(key) => parent.get_mut(*key),
+            Segment::Index(index) => parent.get_mut(*index),
+        }
+        .ok_or_else(invalid_path)?;
+    }
+    let target = match last {
+        Segment::Key(key) => parent.get_mut(*key),
+        Segment::Index(index) => parent.get_mut(*index),
+    }
+    .ok_or_else(invalid_path)?;
+
+    // `null` stands for `None`, so it is left for deserialization to check.
+    if !target.is_null() && !operation.value.is_null() && kind(target) != kind(&operation.value) {
+        return Err(Error::TypeMismatch {
+            path: operation.path.clone(),
+            expected: kind(target),
+            found: kind(&operation.value),
+        });
+    }
+    *target = operation.value.clone();
+
+    // Fields that were missing from an older save are only encoded once they are no longer
+    // marked as defaulted, along with the defaulted fields before them.
+    if let (Segment::Key(key), Some(Value::Array(defaulted))) =
+        (last, parent.pointer_mut("/extra/defaulted"))
+    {
+        if let Some(i) = defaulted.iter().position(|v| v == key) {
+            defaulted.drain(..=i);
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn get(value: &Save, path: &str) -> Result<Value, Error> {
+    let root = serde_json::to_value(value).map_err(|source| Error::InvalidValue {
+        path: String::new(),
+        source,
+    })?;
+    let invalid_path = || Error::InvalidPath { path: path.into() };
+    let mut value = &root;
+    for segment in parse(path).ok_or_else(invalid_path)? {
+        value = match segment {
+            Segment::Key(key) => value.get(key),
+            Segment::Index(index) => value.get(index),
+        }
+        .ok_or_else(invalid_path)?;
+    }
+    Ok(value.clone())
+}
+
+pub(crate) fn apply(value: &Save, operations: &[Operation]) -> Result<Save, Error> {
+    let mut root = serde_json::to_value(value).map_err(|source| Error::InvalidValue {
+        path: String::new(),
+        source,
+    })?;
+    for operation in operations {
+        set(&mut root, operation)?;
+        // Checked after each operation so that the error names the offending path.
+        Save::deserialize(&root).map_err(|source| Error::InvalidValue {
+            path: operation.path.clone(),
+            source,
+        })?;
+    }
+    Save::deserialize(root).map_err(|source| Error::InvalidValue {
+        path: String::new(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Operation;
+    use crate::Error;
+    use serde_json::json;
+
+    fn operation(path: &str, value: serde_json::Value) -> Operation {
+        Operation {
+            path: path.into(),
+            value,
+        }
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_patch() {
+        let code = include_str!("samples/00.txt").trim();
+        let patched = crate::patch(
+            code,
+            &[
+                operation("miscellaneous_game_data.cookies_in_bank", json!(1e10)),
+                operation("upgrades[123].bought", json!(true)),
+                operation("run_details.bakery_name", json!("Test")),
+                operation("miscellaneous_game_data.current_season", json!(null)),
+            ],
+        )
+        .unwrap();
+        let save = crate::decode(&patched).unwrap();
+        assert_eq!(save.miscellaneous_game_data.cookies_in_bank, 1e10);
+        assert!(save.upgrades[123].bought);
+        assert_eq!(save.run_details.bakery_name, "Test");
+        assert_eq!(save.miscellaneous_game_data.current_season, None);
+
+        let error = crate::patch(code, &[operation("upgrades[123].bought", json!("yes"))]);
+        assert!(matches!(error, Err(Error::TypeMismatch { .. })));
+        let error = crate::patch(code, &[operation("upgrades[123].sold", json!(true))]);
+        assert!(matches!(error, Err(Error::InvalidPath { .. })));
+        let error = crate::patch(code, &[operation("upgrades[123.bought", json!(true))]);
+        assert!(matches!(error, Err(Error::InvalidPath { .. })));
+        let error = crate::patch(
+            code,
+            &[operation("building_data.cursors.amount_owned", json!(-1))],
+        );
+        assert!(matches!(error, Err(Error::InvalidValue { .. })));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_patch_non_finite() {
+        let mut save = crate::decode(include_str!("samples/00.txt").trim()).unwrap();
+        save.miscellaneous_game_data.cookies_in_bank = f64::NAN;
+        save.miscellaneous_game_data.cookies_baked = f64::NEG_INFINITY;
+        let code = crate::encode(&save);
+
+        let patched = crate::patch(
+            &code,
+            &[operation("run_details.bakery_name", json!("Test"))],
+        )
+        .unwrap();
+        let patched = crate::decode(&patched).unwrap();
+        assert_eq!(patched.run_details.bakery_name, "Test");
+        assert!(patched.miscellaneous_game_data.cookies_in_bank.is_nan());
+        assert_eq!(
+            patched.miscellaneous_game_data.cookies_baked,
+            f64::NEG_INFINITY
+        );
+        assert_eq!(
+            save.get("miscellaneous_game_data.cookies_in_bank").unwrap(),
+            json!("NaN"),
+        );
+
+        let patched = crate::patch(
+            &code,
+            &[
+                operation("miscellaneous_game_data.cookies_in_bank", json!(5)),
+                operation("miscellaneous_game_data.heavenly_chips", json!("Infinity")),
+            ],
+        )
+        .unwrap();
+        let patched = crate::decode(&patched).unwrap();
+        assert_eq!(patched.miscellaneous_game_data.cookies_in_bank, 5.);
+        assert_eq!(
+            patched.miscellaneous_game_data.heavenly_chips,
+            f64::INFINITY
+        );
+        let error = crate::patch(
+            &code,
+            &[operation(
+                "miscellaneous_game_data.heavenly_chips",
+                json!("lots"),
+            )],
+        );
+        assert!(matches!(error, Err(Error::TypeMismatch { .. })));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::error::Error;
+use crate::format::{self, Format as _, Lazy};
+use crate::{
+    BuildingData, GameBuff, GameVersion, MiscellaneousGameData, Preferences, RunDetails, Save,
+    Upgrade, YouAppearance,
+};
+use chrono::{DateTime, Utc};
+
+/// A save that borrows from its text, see [`decode_text`](crate::decode_text).
+///
+/// The small records are decoded up front. The others are [`Lazy`], so that only the parts
+/// that are read are decoded, and sequences can be scanned without collecting them.
+///
+/// `miscellaneous_game_data` and `building_data` have no borrowed counterparts: decoding
+/// them yields the owned [`MiscellaneousGameData`] and [`BuildingData`], which allocate for
+/// the vault, the minigame data and the layout bookkeeping in [`Extra`](crate::Extra). Both
+/// are mostly numbers, so this costs little next to `upgrades` and `achievements`, but code
+/// that must not allocate should leave them undecoded.
+#[derive(Clone, Debug, format::Format)]
+#[format(split = '|', trailing = true)]
+pub struct SaveRef<'a> {
+    pub game_version: GameVersion,
+    pub empty: &'a str,
+    pub run_details: RunDetailsRef<'a>,
+    pub preferences: Preferences,
+    pub miscellaneous_game_data: Lazy<'a, MiscellaneousGameData>,
+    pub building_data: Lazy<'a, BuildingData>,
+    pub upgrades: Lazy<'a, Vec<Upgrade>, format::Chars<format::Standard, 2>>,
+    pub achievements: Lazy<'a, Vec<bool>, format::Chars<format::Standard>>,
+    pub game_buffs: Lazy<'a, Vec<GameBuff>, format::Seq<format::Standard, ';', 1, true>>,
+}
+
+/// [`RunDetails`] borrowing its strings.
+#[derive(Clone, Debug, format::Format)]
+#[format(split = ';')]
+pub struct RunDetailsRef<'a> {
+    #[format(with = format::Timestamp)]
+    pub ascension_start: DateTime<Utc>,
+    #[format(with = format::Timestamp)]
+    pub legacy_start: DateTime<Utc>,
+    #[format(with = format::Timestamp)]
+    pub last_opened: DateTime<Utc>,
+    pub bakery_name: &'a str,
+    pub seed: &'a str,
+    pub you_appearance: YouAppearance,
+    #[format(extra)]
+    pub extra: format::Extra,
+}
+
+impl<'a> SaveRef<'a> {
+    /// Decodes the save text `value`, borrowing from it.
+    #[tracing::instrument(skip_all, err)]
+    pub fn decode(value: &'a str) -> Result<Self, Error> {
+        format::Standard::decode(value)
+    }
+}
+
+impl TryFrom<&SaveRef<'_>> for Save {
+    type Error = Error;
+
+    /// Decodes the lazy parts of `value`. Offsets in errors are relative to the part.
+    fn try_from(value: &SaveRef<'_>) -> Result<Self, Error> {
+        macro_rules! decode {
+            ($field:ident) => {
+                value.$field.decode().map_err(|error| {
+                    let raw = value.$field.raw();
+                    error.field(stringify!($field), raw, raw)
+                })?
+            };
+        }
+        Ok(Self {
+            game_version: value.game_version,
+            empty: value.empty.to_owned(),
+            run_details: (&value.run_details).into(),
+            preferences: value.preferences.clone(),
+            miscellaneous_game_data: decode!(miscellaneous_game_data),
+            building_data: decode!(building_data),
+            upgrades: decode!(upgrades),
+            achievements: decode!(achievements),
+            game_buffs: decode!(game_buffs),
+        })
+    }
+}
+
+impl From<&RunDetailsRef<'_>> for RunDetails {
+    fn from(value: &RunDetailsRef<'_>) -> Self {
+        Self {
+            ascension_start: value.ascension_start,
+            legacy_start: value.legacy_start,
+            last_opened: value.last_opened,
+            bakery_name: value.bakery_name.to_owned(),
+            seed: value.seed.to_owned(),
+            you_appearance: value.you_appearance.clone(),
+            extra: value.extra.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SaveRef;
+    use crate::{format, Save};
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_save_ref() {
+        for value in [
+            include_str!("samples/00.txt"),
+            include_str!("samples/01.txt"),
+            include_str!("samples/02.txt"),
+        ] {
+            let value = value.trim();
+            let text = crate::decode_text(value).unwrap();
+            format::check_inverse::<'_, '_, format::Standard, SaveRef>(&text).unwrap();
+
+            let save = SaveRef::decode(&text).unwrap();
+            let expected = crate::decode(value).unwrap();
+            assert_eq!(
+                save.run_details.bakery_name,
+                expected.run_details.bakery_name
+            );
+            assert_eq!(
+                save.achievements
+                    .iter()
+                    .filter(|v| *v.as_ref().unwrap())
+                    .count(),
+                expected.achievements.iter().filter(|v| **v).count(),
+            );
+            let save = Save::try_from(&save).unwrap();
+            assert_eq!(crate::encode(&save), crate::encode(&expected));
+        }
+    }
+}
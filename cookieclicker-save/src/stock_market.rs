@@ -0,0 +1,153 @@
+use crate::error::Error;
+use crate::format;
+use crate::{Buff, GameBuff};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StockMarket {
+    pub office_level: usize,
+    pub brokers: u64,
+    pub graph_lines: bool,
+    #[serde(with = "crate::float")]
+    pub profit: f64,
+    pub graph_columns: usize,
+    pub goods: Vec<GoodData>,
+    pub on_minigame: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
+#[format(split = ':')]
+pub struct GoodData {
+    /// in cents
+    pub price: i64,
+    pub mode: GoodMode,
+    /// in cents
+    pub delta: i64,
+    pub mode_duration: u64,
+    pub stock: u64,
+    #[format(default)]
+    pub hidden: bool,
+    #[format(default)]
+    pub last_action: usize,
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, strum::FromRepr, format::Format,
+)]
+#[repr(usize)]
+pub enum GoodMode {
+    #[format(fallback)]
+    Stable,
+    SlowRise,
+    SlowFall,
+    FastRise,
+    FastFall,
+    Chaotic,
+}
+
+/// Loans are not part of the minigame data but are kept as game buffs.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct Loan {
+    pub tier: usize,
+    pub interest: bool,
+    pub time_remaining: u64,
+}
+
+impl GoodData {
+    pub fn price(&self) -> f64 {
+        self.price as f64 / 100.
+    }
+
+    pub fn delta(&self) -> f64 {
+        self.delta as f64 / 100.
+    }
+}
+
+impl Loan {
+    pub fn from_game_buff(value: &GameBuff) -> Option<Self> {
+        let (tier, interest) = match value.buff() {
+            Buff::Loan { tier, .. } => (tier, false),
+            Buff::LoanInterest { tier, .. } => (tier, true),
+            _ => return None,
+        };
+        Some(Self {
+            tier,
+            interest,
+            time_remaining: value.time_remaining,
+        })
+    }
+}
+
+#[derive(format::Format)]
+#[format(split = ' ')]
+struct Format<'a> {
+    #[format(flatten)]
+    inner: Inner,
+    #[format(seq(split = '!', trailing = true))]
+    goods: Cow<'a, [GoodData]>,
+    on_minigame: bool,
+}
+
+#[derive(format::Format)]
+#[format(split = ':', trailing = true)]
+struct Inner {
+    office_level: usize,
+    brokers: u64,
+    graph_lines: bool,
+    profit: f64,
+    graph_columns: usize,
+}
+
+impl format::Format<'_, StockMarket> for format::Standard {
+    fn decode(value: &str) -> Result<StockMarket, Error> {
+        let Format {
+            inner:
+                Inner {
+                    office_level,
+                    brokers,
+                    graph_lines,
+                    profit,
+                    graph_columns,
+                },
+            goods,
+            on_minigame,
+        } = format::Standard::decode(value)?;
+        Ok(StockMarket {
+            office_level,
+            brokers,
+            graph_lines,
+            profit,
+            graph_columns,
+            goods: goods.into(),
+            on_minigame,
+        })
+    }
+
+    fn encode(value: &StockMarket, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let StockMarket {
+            office_level,
+            brokers,
+            graph_lines,
+            profit,
+            graph_columns,
+            ref goods,
+            on_minigame,
+        } = *value;
+        format::Standard::encode(
+            &Format {
+                inner: Inner {
+                    office_level,
+                    brokers,
+                    graph_lines,
+                    profit,
+                    graph_columns,
+                },
+                goods: goods.into(),
+                on_minigame,
+            },
+            f,
+        )
+    }
+}
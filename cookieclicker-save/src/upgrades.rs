@@ -1,35 +1,101 @@
-use crate::error::Error;
+mod catalog;
+
 use crate::format;
-use itertools::Itertools;
+pub use catalog::{UpgradeId, UpgradePool};
 use serde::{Deserialize, Serialize};
-use std::fmt;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, format::Format)]
 pub struct Upgrade {
     pub unlocked: bool,
     pub bought: bool,
 }
 
-pub(crate) struct Custom;
-
-impl format::Format<'_, Vec<Upgrade>> for Custom {
-    #[tracing::instrument(err)]
-    fn decode(value: &str) -> Result<Vec<Upgrade>, Error> {
-        format::chars(value)
-            .tuples()
-            .map(|(unlocked, bought)| {
-                let unlocked = format::Standard::decode(unlocked)?;
-                let bought = format::Standard::decode(bought)?;
-                Ok(Upgrade { unlocked, bought })
-            })
-            .collect()
-    }
-
-    fn encode(value: &Vec<Upgrade>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for v in value {
-            format::Standard::encode(&v.unlocked, f)?;
-            format::Standard::encode(&v.bought, f)?;
+/// A view over `Save::upgrades`, indexed by upgrade ID.
+#[derive(Clone, Copy, Debug)]
+pub struct Upgrades<'a>(&'a [Upgrade]);
+
+impl<'a> Upgrades<'a> {
+    pub fn new(value: &'a [Upgrade]) -> Self {
+        Self(value)
+    }
+
+    pub fn get(&self, upgrade: UpgradeId) -> Option<&'a Upgrade> {
+        self.0.get(upgrade.id())
+    }
+
+    pub fn is_bought(&self, upgrade: UpgradeId) -> bool {
+        self.get(upgrade).map(|v| v.bought).unwrap_or(false)
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Option<(UpgradeId, &'a Upgrade)> {
+        let upgrade = UpgradeId::from_name(name)?;
+        Some((upgrade, self.get(upgrade)?))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (UpgradeId, &'a Upgrade)> + 'a {
+        let this = *self;
+        <UpgradeId as strum::VariantArray>::VARIANTS
+            .iter()
+            .filter_map(move |upgrade| Some((*upgrade, this.get(*upgrade)?)))
+    }
+
+    pub fn by_pool(
+        &self,
+        pool: UpgradePool,
+    ) -> impl Iterator<Item = (UpgradeId, &'a Upgrade)> + 'a {
+        self.iter()
+            .filter(move |(upgrade, _)| upgrade.pool() == pool)
+    }
+
+    pub fn bought(&self) -> impl Iterator<Item = UpgradeId> + 'a {
+        self.iter()
+            .filter(|(_, v)| v.bought)
+            .map(|(upgrade, _)| upgrade)
+    }
+
+    /// Upgrades in the catalog that are not bought, except debug ones.
+    /// See also [`unknown`](Self::unknown).
+    pub fn missing(&self) -> impl Iterator<Item = UpgradeId> + 'a {
+        self.iter()
+            .filter(|(upgrade, v)| !v.bought && upgrade.pool() != UpgradePool::Debug)
+            .map(|(upgrade, _)| upgrade)
+    }
+
+    /// IDs of upgrades that are not in the catalog.
+    pub fn unknown(&self) -> impl Iterator<Item = usize> + 'a {
+        (0..self.0.len()).filter(|id| UpgradeId::from_id(*id).is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpgradeId;
+    use crate::format;
+
+    type Vault = format::Seq<format::Standard, ','>;
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_vault() {
+        format::check_inverse::<'_, '_, Vault, Vec<usize>>("").unwrap();
+        format::check_inverse::<'_, '_, Vault, Vec<usize>>("52").unwrap();
+        format::check_inverse::<'_, '_, Vault, Vec<usize>>("52,53,86").unwrap();
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_catalog() {
+        for value in [
+            include_str!("samples/00.txt"),
+            include_str!("samples/01.txt"),
+            include_str!("samples/02.txt"),
+        ] {
+            let save = crate::decode(value.trim()).unwrap();
+            assert_eq!(save.upgrades().unknown().next(), None);
+            assert_eq!(
+                <UpgradeId as strum::VariantArray>::VARIANTS.len(),
+                save.upgrades.len(),
+            );
         }
-        Ok(())
     }
 }
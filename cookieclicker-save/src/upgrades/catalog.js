@@ -0,0 +1,55 @@
+// Prints the `catalog!` entries of catalog.rs from the game's upgrade table.
+// Run it in the console of the game once it has loaded, then paste the result.
+
+const variant = (name) => {
+  const words = name
+    .normalize("NFD")
+    .replace(/[\u0300-\u036f]/g, "")
+    .replace(/'/g, "")
+    .split(/[^A-Za-z0-9]+/)
+    .filter((word) => word);
+  const ident = words.map((word) => word[0].toUpperCase() + word.slice(1)).join("");
+  return /^[0-9]/.test(ident) ? `N${ident}` : ident;
+};
+
+// Float literals, e.g. `1e2` or `999999.`.
+const price = (value) => {
+  const text = String(value);
+  return /[.e]/.test(text) ? text : `${text}.`;
+};
+
+const pool = (upgrade) => {
+  if (upgrade.kitten) {
+    return "Kitten";
+  }
+  switch (upgrade.pool) {
+    case "":
+      return "Normal";
+    case "cookie":
+      return "Cookie";
+    case "tech":
+      return "Tech";
+    case "prestige":
+    case "prestigeDecor":
+      return "Prestige";
+    case "toggle":
+      return "Toggle";
+    default:
+      // "debug", and "unused", which cannot be bought either.
+      return "Debug";
+  }
+};
+
+const seen = new Set();
+const lines = Game.UpgradesById.map((upgrade) => {
+  let ident = variant(upgrade.dname || upgrade.name);
+  if (seen.has(ident)) {
+    ident = `${ident}${upgrade.id}`;
+  }
+  seen.add(ident);
+  const name = JSON.stringify(upgrade.dname || upgrade.name);
+  const building = upgrade.buildingTie ? `Some(${variant(upgrade.buildingTie.name)})` : "None";
+  const tier = typeof upgrade.tier === "number" ? `Some(${upgrade.tier})` : "None";
+  return `    ${ident} = ${upgrade.id}, ${name}, ${price(upgrade.basePrice)}, ${pool(upgrade)}, ${building}, ${tier};`;
+});
+console.log(lines.join("\n"));
@@ -0,0 +1,966 @@
+use crate::Building;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum UpgradePool {
+    Normal,
+    Cookie,
+    Kitten,
+    Tech,
+    Prestige,
+    Debug,
+    Toggle,
+}
+
+macro_rules! catalog {
+    ($($variant:ident = $id:literal, $name:literal, $price:literal, $pool:ident, $building:expr, $tier:expr;)*) => {
+        // Upgrades are listed by their IDs, i.e. the order in which the game declares them.
+        // The entries are generated with `catalog.js`.
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            Hash,
+            Deserialize,
+            Serialize,
+            strum::FromRepr,
+            strum::VariantArray,
+        )]
+        #[repr(usize)]
+        pub enum UpgradeId {
+            $($variant = $id,)*
+        }
+
+        impl UpgradeId {
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            pub fn price(self) -> f64 {
+                match self {
+                    $(Self::$variant => $price,)*
+                }
+            }
+
+            pub fn pool(self) -> UpgradePool {
+                match self {
+                    $(Self::$variant => UpgradePool::$pool,)*
+                }
+            }
+
+            pub fn building(self) -> Option<Building> {
+                #[allow(unused_imports)]
+                use Building::*;
+                match self {
+                    $(Self::$variant => $building,)*
+                }
+            }
+
+            /// The tier of building upgrades, starting from `1`.
+            pub fn tier(self) -> Option<usize> {
+                match self {
+                    $(Self::$variant => $tier,)*
+                }
+            }
+        }
+    };
+}
+
+impl UpgradeId {
+    pub fn id(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_id(id: usize) -> Option<Self> {
+        Self::from_repr(id)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        <Self as strum::VariantArray>::VARIANTS
+            .iter()
+            .copied()
+            .find(|upgrade| upgrade.name() == name)
+    }
+}
+
+catalog! {
+    ReinforcedIndexFinger = 0, "Reinforced index finger", 1e2, Normal, Some(Cursor), Some(1);
+    CarpalTunnelPreventionCream = 1, "Carpal tunnel prevention cream", 5e2, Normal, Some(Cursor), Some(2);
+    Ambidextrous = 2, "Ambidextrous", 1e4, Normal, Some(Cursor), Some(3);
+    ThousandFingers = 3, "Thousand fingers", 1e5, Normal, Some(Cursor), None;
+    MillionFingers = 4, "Million fingers", 1e7, Normal, Some(Cursor), None;
+    BillionFingers = 5, "Billion fingers", 1e8, Normal, Some(Cursor), None;
+    TrillionFingers = 6, "Trillion fingers", 1e9, Normal, Some(Cursor), None;
+    ForwardsFromGrandma = 7, "Forwards from grandma", 1e3, Normal, Some(Grandma), Some(1);
+    SteelPlatedRollingPins = 8, "Steel-plated rolling pins", 5e3, Normal, Some(Grandma), Some(2);
+    LubricatedDentures = 9, "Lubricated dentures", 5e4, Normal, Some(Grandma), Some(3);
+    CheapHoes = 10, "Cheap hoes", 1.1e4, Normal, Some(Farm), Some(1);
+    Fertilizer = 11, "Fertilizer", 5.5e4, Normal, Some(Farm), Some(2);
+    CookieTrees = 12, "Cookie trees", 5.5e5, Normal, Some(Farm), Some(3);
+    SturdierConveyorBelts = 13, "Sturdier conveyor belts", 1.3e6, Normal, Some(Factory), Some(1);
+    ChildLabor = 14, "Child labor", 6.5e6, Normal, Some(Factory), Some(2);
+    Sweatshop = 15, "Sweatshop", 6.5e7, Normal, Some(Factory), Some(3);
+    SugarGas = 16, "Sugar gas", 1.2e5, Normal, Some(Mine), Some(1);
+    Megadrill = 17, "Megadrill", 6e5, Normal, Some(Mine), Some(2);
+    Ultradrill = 18, "Ultradrill", 6e6, Normal, Some(Mine), Some(3);
+    VanillaNebulae = 19, "Vanilla nebulae", 5.1e10, Normal, Some(Shipment), Some(1);
+    Wormholes = 20, "Wormholes", 2.55e11, Normal, Some(Shipment), Some(2);
+    FrequentFlyer = 21, "Frequent flyer", 2.55e12, Normal, Some(Shipment), Some(3);
+    Antimony = 22, "Antimony", 7.5e11, Normal, Some(AlchemyLab), Some(1);
+    EssenceOfDough = 23, "Essence of dough", 3.75e12, Normal, Some(AlchemyLab), Some(2);
+    TrueChocolate = 24, "True chocolate", 3.75e13, Normal, Some(AlchemyLab), Some(3);
+    AncientTablet = 25, "Ancient tablet", 1e13, Normal, Some(Portal), Some(1);
+    InsaneOatlingWorkers = 26, "Insane oatling workers", 5e13, Normal, Some(Portal), Some(2);
+    SoulBond = 27, "Soul bond", 5e14, Normal, Some(Portal), Some(3);
+    FluxCapacitors = 28, "Flux capacitors", 1.4e14, Normal, Some(TimeMachine), Some(1);
+    TimeParadoxResolver = 29, "Time paradox resolver", 7e14, Normal, Some(TimeMachine), Some(2);
+    QuantumConundrum = 30, "Quantum conundrum", 7e15, Normal, Some(TimeMachine), Some(3);
+    KittenHelpers = 31, "Kitten helpers", 9e6, Kitten, None, None;
+    KittenWorkers = 32, "Kitten workers", 9e9, Kitten, None, None;
+    PlainCookies = 33, "Plain cookies", 999999., Cookie, None, None;
+    SugarCookies = 34, "Sugar cookies", 4999995., Cookie, None, None;
+    OatmealRaisinCookies = 35, "Oatmeal raisin cookies", 9999999., Cookie, None, None;
+    PeanutButterCookies = 36, "Peanut butter cookies", 49999995., Cookie, None, None;
+    CoconutCookies = 37, "Coconut cookies", 99999999., Cookie, None, None;
+    WhiteChocolateCookies = 38, "White chocolate cookies", 99999999., Cookie, None, None;
+    MacadamiaNutCookies = 39, "Macadamia nut cookies", 99999999., Cookie, None, None;
+    DoubleChipCookies = 40, "Double-chip cookies", 4999999995., Cookie, None, None;
+    WhiteChocolateMacadamiaNutCookies = 41, "White chocolate macadamia nut cookies", 9999999999., Cookie, None, None;
+    AllChocolateCookies = 42, "All-chocolate cookies", 49999999995., Cookie, None, None;
+    QuadrillionFingers = 43, "Quadrillion fingers", 1e10, Normal, Some(Cursor), None;
+    PruneJuice = 44, "Prune juice", 5e6, Normal, Some(Grandma), Some(4);
+    GeneticallyModifiedCookies = 45, "Genetically-modified cookies", 5.5e7, Normal, Some(Farm), Some(4);
+    RadiumReactors = 46, "Radium reactors", 6.5e9, Normal, Some(Factory), Some(4);
+    Ultimadrill = 47, "Ultimadrill", 6e8, Normal, Some(Mine), Some(4);
+    WarpDrive = 48, "Warp drive", 2.55e14, Normal, Some(Shipment), Some(4);
+    Ambrosia = 49, "Ambrosia", 3.75e15, Normal, Some(AlchemyLab), Some(4);
+    SanityDance = 50, "Sanity dance", 5e16, Normal, Some(Portal), Some(4);
+    CausalityEnforcer = 51, "Causality enforcer", 7e17, Normal, Some(TimeMachine), Some(4);
+    LuckyDay = 52, "Lucky day", 777777777., Normal, None, None;
+    Serendipity = 53, "Serendipity", 77777777777., Normal, None, None;
+    KittenEngineers = 54, "Kitten engineers", 9e13, Kitten, None, None;
+    DarkChocolateCoatedCookies = 55, "Dark chocolate-coated cookies", 99999999999., Cookie, None, None;
+    WhiteChocolateCoatedCookies = 56, "White chocolate-coated cookies", 99999999999., Cookie, None, None;
+    FarmerGrandmas = 57, "Farmer grandmas", 5.5e4, Normal, Some(Grandma), None;
+    WorkerGrandmas = 58, "Worker grandmas", 6.5e6, Normal, Some(Grandma), None;
+    MinerGrandmas = 59, "Miner grandmas", 6e5, Normal, Some(Grandma), None;
+    CosmicGrandmas = 60, "Cosmic grandmas", 2.55e11, Normal, Some(Grandma), None;
+    TransmutedGrandmas = 61, "Transmuted grandmas", 3.75e12, Normal, Some(Grandma), None;
+    AlteredGrandmas = 62, "Altered grandmas", 5e13, Normal, Some(Grandma), None;
+    GrandmasGrandmas = 63, "Grandmas' grandmas", 7e14, Normal, Some(Grandma), None;
+    BingoCenterResearchFacility = 64, "Bingo center/Research facility", 1e15, Tech, None, None;
+    SpecializedChocolateChips = 65, "Specialized chocolate chips", 1e15, Tech, None, None;
+    DesignerCocoaBeans = 66, "Designer cocoa beans", 2e15, Tech, None, None;
+    RitualRollingPins = 67, "Ritual rolling pins", 4e15, Tech, None, None;
+    UnderworldOvens = 68, "Underworld ovens", 8e15, Tech, None, None;
+    OneMind = 69, "One mind", 1.6e16, Tech, None, None;
+    ExoticNuts = 70, "Exotic nuts", 3.2e16, Tech, None, None;
+    CommunalBrainsweep = 71, "Communal brainsweep", 6.4e16, Tech, None, None;
+    ArcaneSugar = 72, "Arcane sugar", 1.28e17, Tech, None, None;
+    ElderPact = 73, "Elder Pact", 2.56e17, Tech, None, None;
+    ElderPledge = 74, "Elder Pledge", 666666., Toggle, None, None;
+    PlasticMouse = 75, "Plastic mouse", 5e4, Normal, None, Some(1);
+    IronMouse = 76, "Iron mouse", 5e6, Normal, None, Some(2);
+    TitaniumMouse = 77, "Titanium mouse", 5e8, Normal, None, Some(3);
+    AdamantiumMouse = 78, "Adamantium mouse", 5e10, Normal, None, Some(4);
+    Ultrascience = 79, "Ultrascience", 7., Debug, None, None;
+    EclipseCookies = 80, "Eclipse cookies", 499999999999995., Cookie, None, None;
+    ZebraCookies = 81, "Zebra cookies", 999999999999999., Cookie, None, None;
+    QuintillionFingers = 82, "Quintillion fingers", 1e13, Normal, Some(Cursor), None;
+    GoldHoard = 83, "Gold hoard", 7., Debug, None, None;
+    ElderCovenant = 84, "Elder Covenant", 66666666666666., Toggle, None, None;
+    RevokeElderCovenant = 85, "Revoke Elder Covenant", 1e9, Toggle, None, None;
+    GetLucky = 86, "Get lucky", 77777777777777., Normal, None, None;
+    SacrificialRollingPins = 87, "Sacrificial rolling pins", 2888888888888., Normal, None, None;
+    Snickerdoodles = 88, "Snickerdoodles", 999999999999999., Cookie, None, None;
+    Stroopwafels = 89, "Stroopwafels", 999999999999999., Cookie, None, None;
+    Macaroons = 90, "Macaroons", 999999999999999., Cookie, None, None;
+    Neuromancy = 91, "Neuromancy", 7., Debug, None, None;
+    EmpireBiscuits = 92, "Empire biscuits", 1e17, Cookie, None, None;
+    BritishTeaBiscuits = 93, "British tea biscuits", 1e17, Cookie, None, None;
+    ChocolateBritishTeaBiscuits = 94, "Chocolate british tea biscuits", 1e17, Cookie, None, None;
+    RoundBritishTeaBiscuits = 95, "Round british tea biscuits", 1e17, Cookie, None, None;
+    RoundChocolateBritishTeaBiscuits = 96, "Round chocolate british tea biscuits", 1e17, Cookie, None, None;
+    RoundBritishTeaBiscuitsWithHeartMotif = 97, "Round british tea biscuits with heart motif", 1e17, Cookie, None, None;
+    RoundChocolateBritishTeaBiscuitsWithHeartMotif = 98, "Round chocolate british tea biscuits with heart motif", 1e17, Cookie, None, None;
+    SugarBosons = 99, "Sugar bosons", 1.7e15, Normal, Some(AntimatterCondenser), Some(1);
+    StringTheory = 100, "String theory", 8.5e15, Normal, Some(AntimatterCondenser), Some(2);
+    LargeMacaronCollider = 101, "Large macaron collider", 8.5e16, Normal, Some(AntimatterCondenser), Some(3);
+    BigBangBake = 102, "Big bang bake", 8.5e18, Normal, Some(AntimatterCondenser), Some(4);
+    Antigrandmas = 103, "Antigrandmas", 8.5e15, Normal, Some(Grandma), None;
+    Madeleines = 104, "Madeleines", 2e17, Cookie, None, None;
+    Palmiers = 105, "Palmiers", 2e17, Cookie, None, None;
+    Palets = 106, "Palets", 2e17, Cookie, None, None;
+    Sables = 107, "Sablés", 2e17, Cookie, None, None;
+    KittenOverseers = 108, "Kitten overseers", 9e16, Kitten, None, None;
+    SextillionFingers = 109, "Sextillion fingers", 1e16, Normal, Some(Cursor), None;
+    DoubleThickGlasses = 110, "Double-thick glasses", 5e8, Normal, Some(Grandma), Some(5);
+    GingerbreadScarecrows = 111, "Gingerbread scarecrows", 5.5e9, Normal, Some(Farm), Some(5);
+    Recombobulators = 112, "Recombobulators", 6.5e11, Normal, Some(Factory), Some(5);
+    HBombMining = 113, "H-bomb mining", 6e10, Normal, Some(Mine), Some(5);
+    ChocolateMonoliths = 114, "Chocolate monoliths", 2.55e16, Normal, Some(Shipment), Some(5);
+    AquaCrustulae = 115, "Aqua crustulae", 3.75e17, Normal, Some(AlchemyLab), Some(5);
+    BraneTransplant = 116, "Brane transplant", 5e18, Normal, Some(Portal), Some(5);
+    YestermorrowComparators = 117, "Yestermorrow comparators", 7e19, Normal, Some(TimeMachine), Some(5);
+    ReverseCyclotrons = 118, "Reverse cyclotrons", 8.5e20, Normal, Some(AntimatterCondenser), Some(5);
+    UnobtainiumMouse = 119, "Unobtainium mouse", 5e12, Normal, None, Some(5);
+    Caramoas = 120, "Caramoas", 1e18, Cookie, None, None;
+    Sagalongs = 121, "Sagalongs", 1e18, Cookie, None, None;
+    Shortfoils = 122, "Shortfoils", 1e18, Cookie, None, None;
+    WinMints = 123, "Win mints", 1e18, Cookie, None, None;
+    PerfectIdling = 124, "Perfect idling", 7., Debug, None, None;
+    FigGluttons = 125, "Fig gluttons", 1e18, Cookie, None, None;
+    Loreols = 126, "Loreols", 1e18, Cookie, None, None;
+    JaffaCakes = 127, "Jaffa cakes", 1e18, Cookie, None, None;
+    GreasesCups = 128, "Grease's cups", 1e18, Cookie, None, None;
+    HeavenlyChipSecret = 129, "Heavenly chip secret", 11., Prestige, None, None;
+    HeavenlyCookieStand = 130, "Heavenly cookie stand", 1111., Prestige, None, None;
+    HeavenlyBakery = 131, "Heavenly bakery", 11111., Prestige, None, None;
+    HeavenlyConfectionery = 132, "Heavenly confectionery", 111111., Prestige, None, None;
+    HeavenlyKey = 133, "Heavenly key", 1111111., Prestige, None, None;
+    SkullCookies = 134, "Skull cookies", 444444444444., Cookie, None, None;
+    GhostCookies = 135, "Ghost cookies", 444444444444., Cookie, None, None;
+    BatCookies = 136, "Bat cookies", 444444444444., Cookie, None, None;
+    SlimeCookies = 137, "Slime cookies", 444444444444., Cookie, None, None;
+    PumpkinCookies = 138, "Pumpkin cookies", 444444444444., Cookie, None, None;
+    EyeballCookies = 139, "Eyeball cookies", 444444444444., Cookie, None, None;
+    SpiderCookies = 140, "Spider cookies", 444444444444., Cookie, None, None;
+    PersistentMemory = 141, "Persistent memory", 5e2, Prestige, None, None;
+    WrinklerDoormat = 142, "Wrinkler doormat", 7., Debug, None, None;
+    ChristmasTreeBiscuits = 143, "Christmas tree biscuits", 252525252525., Cookie, None, None;
+    SnowflakeBiscuits = 144, "Snowflake biscuits", 252525252525., Cookie, None, None;
+    SnowmanBiscuits = 145, "Snowman biscuits", 252525252525., Cookie, None, None;
+    HollyBiscuits = 146, "Holly biscuits", 252525252525., Cookie, None, None;
+    CandyCaneBiscuits = 147, "Candy cane biscuits", 252525252525., Cookie, None, None;
+    BellBiscuits = 148, "Bell biscuits", 252525252525., Cookie, None, None;
+    PresentBiscuits = 149, "Present biscuits", 252525252525., Cookie, None, None;
+    GingerbreadMen = 150, "Gingerbread men", 2525., Cookie, None, None;
+    GingerbreadTrees = 151, "Gingerbread trees", 2525., Cookie, None, None;
+    AFestiveHat = 152, "A festive hat", 2525., Normal, None, None;
+    IncreasedMerriness = 153, "Increased merriness", 2525., Normal, None, None;
+    ImprovedJolliness = 154, "Improved jolliness", 2525., Normal, None, None;
+    ALumpOfCoal = 155, "A lump of coal", 2525., Normal, None, None;
+    AnItchySweater = 156, "An itchy sweater", 2525., Normal, None, None;
+    ReindeerBakingGrounds = 157, "Reindeer baking grounds", 2525., Normal, None, None;
+    WeightedSleighs = 158, "Weighted sleighs", 2525., Normal, None, None;
+    HoHoHoFlavoredFrosting = 159, "Ho ho ho-flavored frosting", 2525., Normal, None, None;
+    SeasonSavings = 160, "Season savings", 2525., Normal, None, None;
+    ToyWorkshop = 161, "Toy workshop", 2525., Normal, None, None;
+    NaughtyList = 162, "Naughty list", 2525., Normal, None, None;
+    SantasBottomlessBag = 163, "Santa's bottomless bag", 2525., Normal, None, None;
+    SantasHelpers = 164, "Santa's helpers", 2525., Normal, None, None;
+    SantasLegacy = 165, "Santa's legacy", 2525., Normal, None, None;
+    SantasMilkAndCookies = 166, "Santa's milk and cookies", 2525., Normal, None, None;
+    ReindeerSeason = 167, "Reindeer season", 7., Debug, None, None;
+    SantasDominion = 168, "Santa's dominion", 2525252525252525., Normal, None, None;
+    PureHeartBiscuits = 169, "Pure heart biscuits", 1e6, Cookie, None, None;
+    ArdentHeartBiscuits = 170, "Ardent heart biscuits", 1e6, Cookie, None, None;
+    SourHeartBiscuits = 171, "Sour heart biscuits", 1e6, Cookie, None, None;
+    WeepingHeartBiscuits = 172, "Weeping heart biscuits", 1e6, Cookie, None, None;
+    GoldenHeartBiscuits = 173, "Golden heart biscuits", 1e6, Cookie, None, None;
+    EternalHeartBiscuits = 174, "Eternal heart biscuits", 1e6, Cookie, None, None;
+    GemPolish = 175, "Gem polish", 2.1e16, Normal, Some(Prism), Some(1);
+    N9thColor = 176, "9th color", 1.05e17, Normal, Some(Prism), Some(2);
+    ChocolateLight = 177, "Chocolate light", 1.05e18, Normal, Some(Prism), Some(3);
+    Grainbow = 178, "Grainbow", 1.05e20, Normal, Some(Prism), Some(4);
+    PureCosmicLight = 179, "Pure cosmic light", 1.05e22, Normal, Some(Prism), Some(5);
+    RainbowGrandmas = 180, "Rainbow grandmas", 1.05e17, Normal, Some(Grandma), None;
+    SeasonSwitcher = 181, "Season switcher", 1111., Prestige, None, None;
+    FestiveBiscuit = 182, "Festive biscuit", 252525., Toggle, None, None;
+    GhostlyBiscuit = 183, "Ghostly biscuit", 444444., Toggle, None, None;
+    LovesickBiscuit = 184, "Lovesick biscuit", 525252., Toggle, None, None;
+    FoolsBiscuit = 185, "Fool's biscuit", 1e6, Toggle, None, None;
+    EternalSeasons = 186, "Eternal seasons", 7., Debug, None, None;
+    KittenManagers = 187, "Kitten managers", 9e20, Kitten, None, None;
+    SeptillionFingers = 188, "Septillion fingers", 1e19, Normal, Some(Cursor), None;
+    OctillionFingers = 189, "Octillion fingers", 1e22, Normal, Some(Cursor), None;
+    EludiumMouse = 190, "Eludium mouse", 5e14, Normal, None, Some(6);
+    WishalloyMouse = 191, "Wishalloy mouse", 5e16, Normal, None, Some(7);
+    AgingAgents = 192, "Aging agents", 5e10, Normal, Some(Grandma), Some(6);
+    PulsarSprinklers = 193, "Pulsar sprinklers", 5.5e11, Normal, Some(Farm), Some(6);
+    DeepBakeProcess = 194, "Deep-bake process", 6.5e13, Normal, Some(Factory), Some(6);
+    Coreforge = 195, "Coreforge", 6e12, Normal, Some(Mine), Some(6);
+    GenerationShip = 196, "Generation ship", 2.55e18, Normal, Some(Shipment), Some(6);
+    OriginCrucible = 197, "Origin crucible", 3.75e19, Normal, Some(AlchemyLab), Some(6);
+    DeitySizedPortals = 198, "Deity-sized portals", 5e20, Normal, Some(Portal), Some(6);
+    FarFutureEnactment = 199, "Far future enactment", 7e21, Normal, Some(TimeMachine), Some(6);
+    Nanocosmics = 200, "Nanocosmics", 8.5e22, Normal, Some(AntimatterCondenser), Some(6);
+    GlowInTheDark = 201, "Glow-in-the-dark", 1.05e24, Normal, Some(Prism), Some(6);
+    RoseMacarons = 202, "Rose macarons", 9999., Cookie, None, None;
+    LemonMacarons = 203, "Lemon macarons", 9999., Cookie, None, None;
+    ChocolateMacarons = 204, "Chocolate macarons", 9999., Cookie, None, None;
+    PistachioMacarons = 205, "Pistachio macarons", 9999., Cookie, None, None;
+    HazelnutMacarons = 206, "Hazelnut macarons", 9999., Cookie, None, None;
+    VioletMacarons = 207, "Violet macarons", 9999., Cookie, None, None;
+    MagicShenanigans = 208, "Magic shenanigans", 7., Debug, None, None;
+    BunnyBiscuit = 209, "Bunny biscuit", 1e6, Toggle, None, None;
+    ChickenEgg = 210, "Chicken egg", 999., Normal, None, None;
+    DuckEgg = 211, "Duck egg", 999., Normal, None, None;
+    TurkeyEgg = 212, "Turkey egg", 999., Normal, None, None;
+    QuailEgg = 213, "Quail egg", 999., Normal, None, None;
+    RobinEgg = 214, "Robin egg", 999., Normal, None, None;
+    OstrichEgg = 215, "Ostrich egg", 999., Normal, None, None;
+    CassowaryEgg = 216, "Cassowary egg", 999., Normal, None, None;
+    SalmonRoe = 217, "Salmon roe", 999., Normal, None, None;
+    Frogspawn = 218, "Frogspawn", 999., Normal, None, None;
+    SharkTooth = 219, "Shark tooth", 999., Normal, None, None;
+    TurtleEgg = 220, "Turtle egg", 999., Normal, None, None;
+    AntLarva = 221, "Ant larva", 999., Normal, None, None;
+    GoldenGooseEgg = 222, "Golden goose egg", 999., Normal, None, None;
+    FabergeEgg = 223, "Faberge egg", 999., Normal, None, None;
+    Wrinklerspawn = 224, "Wrinklerspawn", 999., Normal, None, None;
+    CookieEgg = 225, "Cookie egg", 999., Normal, None, None;
+    Omelette = 226, "Omelette", 999., Normal, None, None;
+    ChocolateEgg = 227, "Chocolate egg", 999., Normal, None, None;
+    CenturyEgg = 228, "Century egg", 999., Normal, None, None;
+    Egg = 229, "\"egg\"", 999., Normal, None, None;
+    CaramelMacarons = 230, "Caramel macarons", 9999999., Cookie, None, None;
+    LicoriceMacarons = 231, "Licorice macarons", 9999999999., Cookie, None, None;
+    TallerTellers = 232, "Taller tellers", 1.4e7, Normal, Some(Bank), Some(1);
+    ScissorResistantCreditCards = 233, "Scissor-resistant credit cards", 7e7, Normal, Some(Bank), Some(2);
+    AcidProofVaults = 234, "Acid-proof vaults", 7e8, Normal, Some(Bank), Some(3);
+    ChocolateCoins = 235, "Chocolate coins", 7e10, Normal, Some(Bank), Some(4);
+    ExponentialInterestRates = 236, "Exponential interest rates", 7e12, Normal, Some(Bank), Some(5);
+    FinancialZen = 237, "Financial zen", 7e14, Normal, Some(Bank), Some(6);
+    GoldenIdols = 238, "Golden idols", 2e8, Normal, Some(Temple), Some(1);
+    Sacrifices = 239, "Sacrifices", 1e9, Normal, Some(Temple), Some(2);
+    DeliciousBlessing = 240, "Delicious blessing", 1e10, Normal, Some(Temple), Some(3);
+    SunFestival = 241, "Sun festival", 1e12, Normal, Some(Temple), Some(4);
+    EnlargedPantheon = 242, "Enlarged pantheon", 1e14, Normal, Some(Temple), Some(5);
+    GreatBakerInTheSky = 243, "Great Baker in the sky", 1e16, Normal, Some(Temple), Some(6);
+    PointierHats = 244, "Pointier hats", 3.3e9, Normal, Some(WizardTower), Some(1);
+    BeardlierBeards = 245, "Beardlier beards", 1.65e10, Normal, Some(WizardTower), Some(2);
+    AncientGrimoires = 246, "Ancient grimoires", 1.65e11, Normal, Some(WizardTower), Some(3);
+    KitchenCurses = 247, "Kitchen curses", 1.65e13, Normal, Some(WizardTower), Some(4);
+    SchoolOfSorcery = 248, "School of sorcery", 1.65e15, Normal, Some(WizardTower), Some(5);
+    DarkFormulas = 249, "Dark formulas", 1.65e17, Normal, Some(WizardTower), Some(6);
+    BankerGrandmas = 250, "Banker grandmas", 7e7, Normal, Some(Grandma), None;
+    PriestessGrandmas = 251, "Priestess grandmas", 1e9, Normal, Some(Grandma), None;
+    WitchGrandmas = 252, "Witch grandmas", 1.65e10, Normal, Some(Grandma), None;
+    TinOfBritishTeaBiscuits = 253, "Tin of british tea biscuits", 25., Prestige, None, None;
+    BoxOfMacarons = 254, "Box of macarons", 25., Prestige, None, None;
+    BoxOfBrandBiscuits = 255, "Box of brand biscuits", 25., Prestige, None, None;
+    PureBlackChocolateCookies = 256, "Pure black chocolate cookies", 1e19, Cookie, None, None;
+    PureWhiteChocolateCookies = 257, "Pure white chocolate cookies", 1e19, Cookie, None, None;
+    Ladyfingers = 258, "Ladyfingers", 1e20, Cookie, None, None;
+    Tuiles = 259, "Tuiles", 1e20, Cookie, None, None;
+    ChocolateStuffedBiscuits = 260, "Chocolate-stuffed biscuits", 1e20, Cookie, None, None;
+    CheckerCookies = 261, "Checker cookies", 1e21, Cookie, None, None;
+    ButterCookies = 262, "Butter cookies", 1e21, Cookie, None, None;
+    CreamCookies = 263, "Cream cookies", 1e21, Cookie, None, None;
+    PermanentUpgradeSlotI = 264, "Permanent upgrade slot I", 1e2, Prestige, None, None;
+    PermanentUpgradeSlotII = 265, "Permanent upgrade slot II", 2e4, Prestige, None, None;
+    PermanentUpgradeSlotIII = 266, "Permanent upgrade slot III", 3e6, Prestige, None, None;
+    PermanentUpgradeSlotIV = 267, "Permanent upgrade slot IV", 4e8, Prestige, None, None;
+    PermanentUpgradeSlotV = 268, "Permanent upgrade slot V", 5e10, Prestige, None, None;
+    TwinGatesOfTranscendence = 269, "Twin Gates of Transcendence", 1., Prestige, None, None;
+    Angels = 270, "Angels", 7., Prestige, None, None;
+    Archangels = 271, "Archangels", 49., Prestige, None, None;
+    Virtues = 272, "Virtues", 343., Prestige, None, None;
+    Dominions = 273, "Dominions", 2401., Prestige, None, None;
+    Cherubim = 274, "Cherubim", 16807., Prestige, None, None;
+    Seraphim = 275, "Seraphim", 117649., Prestige, None, None;
+    God = 276, "God", 823543., Prestige, None, None;
+    Belphegor = 277, "Belphegor", 11., Prestige, None, None;
+    Mammon = 278, "Mammon", 121., Prestige, None, None;
+    Abaddon = 279, "Abaddon", 1331., Prestige, None, None;
+    Satan = 280, "Satan", 14641., Prestige, None, None;
+    Asmodeus = 281, "Asmodeus", 161051., Prestige, None, None;
+    Beelzebub = 282, "Beelzebub", 1771561., Prestige, None, None;
+    Lucifer = 283, "Lucifer", 19487171., Prestige, None, None;
+    HeavenlyLuck = 284, "Heavenly luck", 77., Prestige, None, None;
+    LastingFortune = 285, "Lasting fortune", 777., Prestige, None, None;
+    DecisiveFate = 286, "Decisive fate", 7777., Prestige, None, None;
+    DivineDiscount = 287, "Divine discount", 99999., Prestige, None, None;
+    DivineSales = 288, "Divine sales", 99999., Prestige, None, None;
+    DivineBakeries = 289, "Divine bakeries", 399999., Prestige, None, None;
+    StarterKit = 290, "Starter kit", 5e1, Prestige, None, None;
+    StarterKitchen = 291, "Starter kitchen", 5e3, Prestige, None, None;
+    HaloGloves = 292, "Halo gloves", 55555., Prestige, None, None;
+    KittenAngels = 293, "Kitten angels", 9e3, Prestige, None, None;
+    XtremeWalkers = 294, "Xtreme walkers", 5e13, Normal, Some(Grandma), Some(7);
+    FudgeFungus = 295, "Fudge fungus", 5.5e14, Normal, Some(Farm), Some(7);
+    Planetsplitters = 296, "Planetsplitters", 6e15, Normal, Some(Mine), Some(7);
+    CyborgWorkforce = 297, "Cyborg workforce", 6.5e16, Normal, Some(Factory), Some(7);
+    WayOfTheWallet = 298, "Way of the wallet", 7e17, Normal, Some(Bank), Some(7);
+    CreationMyth = 299, "Creation myth", 1e19, Normal, Some(Temple), Some(7);
+    Cookiemancy = 300, "Cookiemancy", 1.65e20, Normal, Some(WizardTower), Some(7);
+    DysonSphere = 301, "Dyson sphere", 2.55e21, Normal, Some(Shipment), Some(7);
+    TheoryOfAtomicFluidity = 302, "Theory of atomic fluidity", 3.75e22, Normal, Some(AlchemyLab), Some(7);
+    EndOfTimesBackUpPlan = 303, "End of times back-up plan", 5e23, Normal, Some(Portal), Some(7);
+    GreatLoopHypothesis = 304, "Great loop hypothesis", 7e24, Normal, Some(TimeMachine), Some(7);
+    ThePulse = 305, "The Pulse", 8.5e25, Normal, Some(AntimatterCondenser), Some(7);
+    LuxSanctorum = 306, "Lux sanctorum", 1.05e27, Normal, Some(Prism), Some(7);
+    UnholyBait = 307, "Unholy bait", 44444., Prestige, None, None;
+    SacrilegiousCorruption = 308, "Sacrilegious corruption", 444444., Prestige, None, None;
+    Starspawn = 309, "Starspawn", 111111., Prestige, None, None;
+    Starsnow = 310, "Starsnow", 111111., Prestige, None, None;
+    Starterror = 311, "Starterror", 111111., Prestige, None, None;
+    Starlove = 312, "Starlove", 111111., Prestige, None, None;
+    Startrade = 313, "Startrade", 111111., Prestige, None, None;
+    NonillionFingers = 314, "Nonillion fingers", 1e25, Normal, Some(Cursor), None;
+    GoldenCookieAlertSound = 315, "Golden cookie alert sound", 9999., Prestige, None, None;
+    KittenAccountants = 316, "Kitten accountants", 9e23, Kitten, None, None;
+    KittenSpecialists = 317, "Kitten specialists", 9e26, Kitten, None, None;
+    KittenExperts = 318, "Kitten experts", 9e29, Kitten, None, None;
+    MilkChocolateButterBiscuit = 319, "Milk chocolate butter biscuit", 1e21, Cookie, None, None;
+    DarkChocolateButterBiscuit = 320, "Dark chocolate butter biscuit", 1e24, Cookie, None, None;
+    WhiteChocolateButterBiscuit = 321, "White chocolate butter biscuit", 1e27, Cookie, None, None;
+    RubyChocolateButterBiscuit = 322, "Ruby chocolate butter biscuit", 1e30, Cookie, None, None;
+    HowToBakeYourDragon = 323, "How to bake your dragon", 9., Prestige, None, None;
+    ACrumblyEgg = 324, "A crumbly egg", 25., Normal, None, None;
+    Chimera = 325, "Chimera", 5e13, Prestige, None, None;
+    TinOfButterCookies = 326, "Tin of butter cookies", 25., Prestige, None, None;
+    GoldenSwitch = 327, "Golden switch", 999., Prestige, None, None;
+    ClassicDairySelection = 328, "Classic dairy selection", 9., Prestige, None, None;
+    FancifulDairySelection = 329, "Fanciful dairy selection", 1e6, Prestige, None, None;
+    DragonCookie = 330, "Dragon cookie", 1e27, Cookie, None, None;
+    GoldenSwitchOff = 331, "Golden switch [off]", 1e6, Toggle, None, None;
+    GoldenSwitchOn = 332, "Golden switch [on]", 1e6, Toggle, None, None;
+    MilkSelector = 333, "Milk selector", 0., Toggle, None, None;
+    LuckyDigit = 334, "Lucky digit", 777., Prestige, None, None;
+    LuckyNumber = 335, "Lucky number", 77777., Prestige, None, None;
+    LuckyPayout = 336, "Lucky payout", 77777777., Prestige, None, None;
+    EyeOfTheWrinkler = 337, "Eye of the wrinkler", 99999999., Prestige, None, None;
+    Gingersnaps = 338, "Gingersnaps", 1e20, Cookie, None, None;
+    CinnamonCookies = 339, "Cinnamon cookies", 1e20, Cookie, None, None;
+    VanityCookies = 340, "Vanity cookies", 1e21, Cookie, None, None;
+    Cigars = 341, "Cigars", 1e21, Cookie, None, None;
+    PinwheelCookies = 342, "Pinwheel cookies", 1e22, Cookie, None, None;
+    FudgeSquares = 343, "Fudge squares", 1e22, Cookie, None, None;
+    Digits = 344, "Digits", 999999999999999., Cookie, None, None;
+    ButterHorseshoes = 345, "Butter horseshoes", 4e21, Cookie, None, None;
+    ButterPucks = 346, "Butter pucks", 4e21, Cookie, None, None;
+    ButterKnots = 347, "Butter knots", 4e21, Cookie, None, None;
+    ButterSlabs = 348, "Butter slabs", 4e21, Cookie, None, None;
+    ButterSwirls = 349, "Butter swirls", 4e21, Cookie, None, None;
+    ShortbreadBiscuits = 350, "Shortbread biscuits", 1e23, Cookie, None, None;
+    MillionairesShortbreads = 351, "Millionaires' shortbreads", 1e23, Cookie, None, None;
+    CaramelCookies = 352, "Caramel cookies", 1e24, Cookie, None, None;
+    PecanSandies = 353, "Pecan sandies", 1e25, Cookie, None, None;
+    MoravianSpiceCookies = 354, "Moravian spice cookies", 1e25, Cookie, None, None;
+    AnzacBiscuits = 355, "Anzac biscuits", 1e25, Cookie, None, None;
+    Buttercakes = 356, "Buttercakes", 1e25, Cookie, None, None;
+    IceCreamSandwiches = 357, "Ice cream sandwiches", 1e25, Cookie, None, None;
+    InspiredChecklist = 358, "Inspired checklist", 9e5, Prestige, None, None;
+    LabelPrinter = 359, "Label printer", 999999., Prestige, None, None;
+    GeniusAccounting = 360, "Genius accounting", 2e6, Prestige, None, None;
+    BackgroundSelector = 361, "Background selector", 0., Toggle, None, None;
+    BasicWallpaperAssortment = 362, "Basic wallpaper assortment", 99., Prestige, None, None;
+    Legacy = 363, "Legacy", 1., Prestige, None, None;
+    ElderSpice = 364, "Elder spice", 444444., Prestige, None, None;
+    ResidualLuck = 365, "Residual luck", 99999., Prestige, None, None;
+    FantasteelMouse = 366, "Fantasteel mouse", 5e18, Normal, None, Some(8);
+    NevercrackMouse = 367, "Nevercrack mouse", 5e20, Normal, None, Some(9);
+    FiveFingerDiscount = 368, "Five-finger discount", 555555., Prestige, None, None;
+    FutureAlmanacs = 369, "Future almanacs", 2.80000000022e18, Normal, Some(Farm), None;
+    RainPrayer = 370, "Rain prayer", 4.00022e18, Normal, Some(Farm), None;
+    SeismicMagic = 371, "Seismic magic", 6.60024e13, Normal, Some(Mine), None;
+    AsteroidMining = 372, "Asteroid mining", 1.0200024e21, Normal, Some(Mine), None;
+    QuantumElectronics = 373, "Quantum electronics", 3.4000000026e19, Normal, Some(Factory), None;
+    TemporalOverclocking = 374, "Temporal overclocking", 2.800000026e24, Normal, Some(Factory), None;
+    ContractsFromBeyond = 375, "Contracts from beyond", 2.0000028e17, Normal, Some(Bank), None;
+    PrintingPresses = 376, "Printing presses", 3.06e17, Normal, Some(Bank), None;
+    Paganism = 377, "Paganism", 2.00004e17, Normal, Some(Temple), None;
+    GodParticle = 378, "God particle", 3.4000004e25, Normal, Some(Temple), None;
+    ArcaneKnowledge = 379, "Arcane knowledge", 1.5066e16, Normal, Some(WizardTower), None;
+    MagicalBotany = 380, "Magical botany", 6.600022e19, Normal, Some(WizardTower), None;
+    FossilFuels = 381, "Fossil fuels", 1.0200024e15, Normal, Some(Shipment), None;
+    Shipyards = 382, "Shipyards", 1.020026e21, Normal, Some(Shipment), None;
+    PrimordialOres = 383, "Primordial ores", 1.50000024e16, Normal, Some(AlchemyLab), None;
+    GoldFund = 384, "Gold fund", 1.500028e22, Normal, Some(AlchemyLab), None;
+    InfernalCrops = 385, "Infernal crops", 2.0000000022e17, Normal, Some(Portal), None;
+    AbysmalGlimmer = 386, "Abysmal glimmer", 4.202e26, Normal, Some(Portal), None;
+    RelativisticParsecSkipping = 387, "Relativistic parsec-skipping", 2.80102e18, Normal, Some(TimeMachine), None;
+    PrimevalGlow = 388, "Primeval glow", 4.228e26, Normal, Some(TimeMachine), None;
+    ExtraPhysicsFunding = 389, "Extra physics funding", 3.400000028e19, Normal, Some(AntimatterCondenser), None;
+    ChemicalProficiency = 390, "Chemical proficiency", 3.4015e25, Normal, Some(AntimatterCondenser), None;
+    LightMagic = 391, "Light magic", 4.20000066e20, Normal, Some(Prism), None;
+    MysticalEnergies = 392, "Mystical energies", 4.20000004e26, Normal, Some(Prism), None;
+    SynergiesVolI = 393, "Synergies Vol. I", 2525., Prestige, None, None;
+    SynergiesVolII = 394, "Synergies Vol. II", 252525., Prestige, None, None;
+    HeavenlyCookies = 395, "Heavenly cookies", 3., Prestige, None, None;
+    WrinklyCookies = 396, "Wrinkly cookies", 6666666., Prestige, None, None;
+    DistilledEssenceOfRedoubledLuck = 397, "Distilled essence of redoubled luck", 7777777., Prestige, None, None;
+    OccultObstruction = 398, "Occult obstruction", 7., Debug, None, None;
+    GlucoseChargedAir = 399, "Glucose-charged air", 7., Debug, None, None;
+    TheUnbridling = 400, "The Unbridling", 5e16, Normal, Some(Grandma), Some(8);
+    WheatTriffids = 401, "Wheat triffids", 5.5e17, Normal, Some(Farm), Some(8);
+    CanolaOilWells = 402, "Canola oil wells", 6e18, Normal, Some(Mine), Some(8);
+    N78HourDays = 403, "78-hour days", 6.5e19, Normal, Some(Factory), Some(8);
+    TheStuffRationale = 404, "The stuff rationale", 7e20, Normal, Some(Bank), Some(8);
+    Theocracy = 405, "Theocracy", 1e22, Normal, Some(Temple), Some(8);
+    RabbitTrick = 406, "Rabbit trick", 1.65e23, Normal, Some(WizardTower), Some(8);
+    TheFinalFrontier = 407, "The final frontier", 2.55e24, Normal, Some(Shipment), Some(8);
+    BeigeGoo = 408, "Beige goo", 3.75e25, Normal, Some(AlchemyLab), Some(8);
+    MaddeningChants = 409, "Maddening chants", 5e26, Normal, Some(Portal), Some(8);
+    CookietopianMomentsOfMaybe = 410, "Cookietopian moments of maybe", 7e27, Normal, Some(TimeMachine), Some(8);
+    SomeOtherSuperTinyFundamentalParticleProbably = 411, "Some other super-tiny fundamental particle? Probably?", 8.5e28, Normal, Some(AntimatterCondenser), Some(8);
+    ReverseShadows = 412, "Reverse shadows", 1.05e30, Normal, Some(Prism), Some(8);
+    ShimmeringVeilOff = 413, "Shimmering veil [off]", 1e6, Toggle, None, None;
+    ShimmeringVeilOn = 414, "Shimmering veil [on]", 1e6, Toggle, None, None;
+    YourLuckyCookie = 415, "Your lucky cookie", 2.6e17, Normal, Some(Chancemaker), Some(1);
+    AllBetsAreOffMagicCoin = 416, "\"All Bets Are Off\" magic coin", 1.3e18, Normal, Some(Chancemaker), Some(2);
+    WinningLotteryTicket = 417, "Winning lottery ticket", 1.3e19, Normal, Some(Chancemaker), Some(3);
+    FourLeafCloverField = 418, "Four-leaf clover field", 1.3e21, Normal, Some(Chancemaker), Some(4);
+    ARecipeBookAboutBooks = 419, "A recipe book about books", 1.3e23, Normal, Some(Chancemaker), Some(5);
+    LeprechaunVillage = 420, "Leprechaun village", 1.3e25, Normal, Some(Chancemaker), Some(6);
+    ImprobabilityDrive = 421, "Improbability drive", 1.3e28, Normal, Some(Chancemaker), Some(7);
+    Antisuperstistronics = 422, "Antisuperstistronics", 1.3e31, Normal, Some(Chancemaker), Some(8);
+    LuckyGrandmas = 423, "Lucky grandmas", 1.3e18, Normal, Some(Grandma), None;
+    GemmedTalismans = 424, "Gemmed talismans", 5.2000000000024e21, Normal, Some(Chancemaker), None;
+    KittenConsultants = 425, "Kitten consultants", 9e32, Kitten, None, None;
+    BirthdayCookie = 426, "Birthday cookie", 1e26, Cookie, None, None;
+    ArmythrilMouse = 427, "Armythril mouse", 5e22, Normal, None, Some(10);
+    ReverseDementia = 428, "Reverse dementia", 5e19, Normal, Some(Grandma), Some(9);
+    HumanePesticides = 429, "Humane pesticides", 5.5e20, Normal, Some(Farm), Some(9);
+    MolePeople = 430, "Mole people", 6e21, Normal, Some(Mine), Some(9);
+    MachineLearning = 431, "Machine learning", 6.5e22, Normal, Some(Factory), Some(9);
+    EdibleMoney = 432, "Edible money", 7e23, Normal, Some(Bank), Some(9);
+    SickRapPrayers = 433, "Sick rap prayers", 1e25, Normal, Some(Temple), Some(9);
+    DeluxeTailoredWands = 434, "Deluxe tailored wands", 1.65e26, Normal, Some(WizardTower), Some(9);
+    Autopilot = 435, "Autopilot", 2.55e27, Normal, Some(Shipment), Some(9);
+    TheAdventOfChemistry = 436, "The advent of chemistry", 3.75e28, Normal, Some(AlchemyLab), Some(9);
+    TheRealWorld = 437, "The real world", 5e29, Normal, Some(Portal), Some(9);
+    SecondSeconds = 438, "Second seconds", 7e30, Normal, Some(TimeMachine), Some(9);
+    QuantumComb = 439, "Quantum comb", 8.5e31, Normal, Some(AntimatterCondenser), Some(9);
+    CrystalMirrors = 440, "Crystal mirrors", 1.05e33, Normal, Some(Prism), Some(9);
+    Bunnypedes = 441, "Bunnypedes", 1.3e34, Normal, Some(Chancemaker), Some(9);
+    KittenAssistantsToTheRegionalManager = 442, "Kitten assistants to the regional manager", 9e35, Kitten, None, None;
+    CharmQuarks = 443, "Charm quarks", 5.234e27, Normal, Some(Chancemaker), None;
+    PinkBiscuits = 444, "Pink biscuits", 1e27, Cookie, None, None;
+    WholeGrainCookies = 445, "Whole-grain cookies", 1e27, Cookie, None, None;
+    CandyCookies = 446, "Candy cookies", 1e27, Cookie, None, None;
+    BigChipCookies = 447, "Big chip cookies", 1e27, Cookie, None, None;
+    OneChipCookies = 448, "One chip cookies", 1e27, Cookie, None, None;
+    SugarBaking = 449, "Sugar baking", 2e8, Prestige, None, None;
+    SugarCraving = 450, "Sugar craving", 4e8, Prestige, None, None;
+    SugarAgingProcess = 451, "Sugar aging process", 6e8, Prestige, None, None;
+    SugarFrenzy = 452, "Sugar frenzy", 0., Normal, None, None;
+    SugarCrystalCookies = 453, "Sugar crystal cookies", 1e9, Cookie, None, None;
+    BoxOfMaybeCookies = 454, "Box of maybe cookies", 3.33e11, Prestige, None, None;
+    BoxOfNotCookies = 455, "Box of not cookies", 3.33e11, Prestige, None, None;
+    BoxOfPastries = 456, "Box of pastries", 3.33e11, Prestige, None, None;
+    Profiteroles = 457, "Profiteroles", 1e31, Cookie, None, None;
+    JellyDonut = 458, "Jelly donut", 1e31, Cookie, None, None;
+    GlazedDonut = 459, "Glazed donut", 1e31, Cookie, None, None;
+    ChocolateCake = 460, "Chocolate cake", 1e31, Cookie, None, None;
+    StrawberryCheesecake = 461, "Strawberry cheesecake", 1e31, Cookie, None, None;
+    ApplePie = 462, "Apple pie", 1e31, Cookie, None, None;
+    LemonMeringuePie = 463, "Lemon meringue pie", 1e31, Cookie, None, None;
+    ButterCroissant = 464, "Butter croissant", 1e31, Cookie, None, None;
+    CookieDough = 465, "Cookie dough", 1e32, Cookie, None, None;
+    BurntCookie = 466, "Burnt cookie", 1e32, Cookie, None, None;
+    AChocolateChipCookieButWithTheChipsPickedOffForSomeReason = 467, "A chocolate chip cookie but with the chips picked off for some reason", 1e32, Cookie, None, None;
+    FlavorTextCookie = 468, "Flavor text cookie", 1e32, Cookie, None, None;
+    HighDefinitionCookie = 469, "High-definition cookie", 1e32, Cookie, None, None;
+    Toast = 470, "Toast", 1e33, Cookie, None, None;
+    PeanutButterJelly = 471, "Peanut butter & jelly", 1e33, Cookie, None, None;
+    Wookies = 472, "Wookies", 1e33, Cookie, None, None;
+    Cheeseburger = 473, "Cheeseburger", 1e33, Cookie, None, None;
+    OneLoneChocolateChip = 474, "One lone chocolate chip", 1e33, Cookie, None, None;
+    SteviaCaelestis = 475, "Stevia Caelestis", 1e8, Prestige, None, None;
+    DiabeticaDaemonicus = 476, "Diabetica Daemonicus", 3e8, Prestige, None, None;
+    SucralosiaInutilis = 477, "Sucralosia Inutilis", 1e9, Prestige, None, None;
+    ShimmeringVeil = 478, "Shimmering veil", 999999999., Prestige, None, None;
+    CosmicBeginnersLuck = 479, "Cosmic beginner's luck", 14999999985., Prestige, None, None;
+    ReinforcedMembrane = 480, "Reinforced membrane", 14999999985., Prestige, None, None;
+    TimeproofHairDyes = 481, "Timeproof hair dyes", 5e22, Normal, Some(Grandma), Some(10);
+    Barnstars = 482, "Barnstars", 5.5e23, Normal, Some(Farm), Some(10);
+    MineCanaries = 483, "Mine canaries", 6e24, Normal, Some(Mine), Some(10);
+    BrowniePointSystem = 484, "Brownie point system", 6.5e25, Normal, Some(Factory), Some(10);
+    GrandSupercycle = 485, "Grand supercycle", 7e26, Normal, Some(Bank), Some(10);
+    PsalmReading = 486, "Psalm-reading", 1e28, Normal, Some(Temple), Some(10);
+    ImmobileSpellcasting = 487, "Immobile spellcasting", 1.65e29, Normal, Some(WizardTower), Some(10);
+    RestaurantsAtTheEndOfTheUniverse = 488, "Restaurants at the end of the universe", 2.55e30, Normal, Some(Shipment), Some(10);
+    OnSecondThought = 489, "On second thought", 3.75e31, Normal, Some(AlchemyLab), Some(10);
+    DimensionalGarbageGulper = 490, "Dimensional garbage gulper", 5e32, Normal, Some(Portal), Some(10);
+    AdditionalClockHands = 491, "Additional clock hands", 7e33, Normal, Some(TimeMachine), Some(10);
+    BakingNobelPrize = 492, "Baking Nobel prize", 8.5e34, Normal, Some(AntimatterCondenser), Some(10);
+    ReverseTheoryOfLight = 493, "Reverse theory of light", 1.05e36, Normal, Some(Prism), Some(10);
+    RevisedProbabilistics = 494, "Revised probabilistics", 1.3e37, Normal, Some(Chancemaker), Some(10);
+    Metabakeries = 495, "Metabakeries", 3.1e18, Normal, Some(FractalEngine), Some(1);
+    MandelbrownSugar = 496, "Mandelbrown sugar", 1.55e19, Normal, Some(FractalEngine), Some(2);
+    Fractoids = 497, "Fractoids", 1.55e20, Normal, Some(FractalEngine), Some(3);
+    NestedUniverseTheory = 498, "Nested universe theory", 1.55e22, Normal, Some(FractalEngine), Some(4);
+    MengerSpongeCake = 499, "Menger sponge cake", 1.55e24, Normal, Some(FractalEngine), Some(5);
+    OneParticularlyGoodHumoredCow = 500, "One particularly good-humored cow", 1.55e26, Normal, Some(FractalEngine), Some(6);
+    ChocolateOuroboros = 501, "Chocolate ouroboros", 1.55e29, Normal, Some(FractalEngine), Some(7);
+    Nested = 502, "Nested", 1.55e32, Normal, Some(FractalEngine), Some(8);
+    SpaceFillingFibers = 503, "Space-filling fibers", 1.55e35, Normal, Some(FractalEngine), Some(9);
+    EndlessBookOfProse = 504, "Endless book of prose", 1.55e38, Normal, Some(FractalEngine), Some(10);
+    Metagrandmas = 505, "Metagrandmas", 1.55e19, Normal, Some(Grandma), None;
+    MiceClickingMice = 506, "Mice clicking mice", 6.2e28, Normal, Some(FractalEngine), None;
+    RecursiveMirrors = 507, "Recursive mirrors", 6.242e22, Normal, Some(FractalEngine), None;
+    DecillionFingers = 508, "Decillion fingers", 1e28, Normal, Some(Cursor), None;
+    TechnobsidianMouse = 509, "Technobsidian mouse", 5e24, Normal, None, Some(11);
+    KittenMarketeers = 510, "Kitten marketeers", 9e38, Kitten, None, None;
+    LavenderChocolateButterBiscuit = 511, "Lavender chocolate butter biscuit", 1e33, Cookie, None, None;
+    SyntheticChocolateGreenHoneyButterBiscuit = 512, "Synthetic chocolate green honey butter biscuit", 1e33, Cookie, None, None;
+    RoyalRaspberryChocolateButterBiscuit = 513, "Royal raspberry chocolate butter biscuit", 1e33, Cookie, None, None;
+    UltraConcentratedHighEnergyChocolateButterBiscuit = 514, "Ultra-concentrated high-energy chocolate butter biscuit", 1e33, Cookie, None, None;
+    FortuneCookies = 515, "Fortune cookies", 77777777777., Prestige, None, None;
+    Fortune001 = 516, "Fortune #001", 1.5e13, Normal, None, None;
+    Fortune002 = 517, "Fortune #002", 1e14, Normal, None, None;
+    Fortune003 = 518, "Fortune #003", 1.1e15, Normal, None, None;
+    Fortune004 = 519, "Fortune #004", 1.2e16, Normal, None, None;
+    Fortune005 = 520, "Fortune #005", 1.3e17, Normal, None, None;
+    Fortune006 = 521, "Fortune #006", 1.4e18, Normal, None, None;
+    Fortune007 = 522, "Fortune #007", 2e19, Normal, None, None;
+    Fortune008 = 523, "Fortune #008", 3.3e20, Normal, None, None;
+    Fortune009 = 524, "Fortune #009", 5.1e21, Normal, None, None;
+    Fortune010 = 525, "Fortune #010", 7.5e22, Normal, None, None;
+    Fortune011 = 526, "Fortune #011", 1e24, Normal, None, None;
+    Fortune012 = 527, "Fortune #012", 1.4e25, Normal, None, None;
+    Fortune013 = 528, "Fortune #013", 1.7e26, Normal, None, None;
+    Fortune014 = 529, "Fortune #014", 2.1e27, Normal, None, None;
+    Fortune015 = 530, "Fortune #015", 2.6e28, Normal, None, None;
+    Fortune016 = 531, "Fortune #016", 3.1e29, Normal, None, None;
+    Fortune100 = 532, "Fortune #100", 1e15, Normal, None, None;
+    Fortune101 = 533, "Fortune #101", 1e15, Normal, None, None;
+    Fortune102 = 534, "Fortune #102", 1e15, Normal, None, None;
+    Fortune103 = 535, "Fortune #103", 1e15, Normal, None, None;
+    Fortune104 = 536, "Fortune #104", 1e15, Normal, None, None;
+    GoldenCookieSoundSelector = 537, "Golden cookie sound selector", 0., Toggle, None, None;
+    ElderwortBiscuits = 538, "Elderwort biscuits", 6e13, Cookie, None, None;
+    BakeberryCookies = 539, "Bakeberry cookies", 6e13, Cookie, None, None;
+    DuketaterCookies = 540, "Duketater cookies", 6e13, Cookie, None, None;
+    GreenYeastDigestives = 541, "Green yeast digestives", 6e13, Cookie, None, None;
+    FernTea = 542, "Fern tea", 6e1, Normal, None, None;
+    IchorSyrup = 543, "Ichor syrup", 3.6e3, Normal, None, None;
+    WheatSlims = 544, "Wheat slims", 1.8e3, Cookie, None, None;
+    Heralds = 545, "Heralds", 1e2, Prestige, None, None;
+    Keepsakes = 546, "Keepsakes", 1111111111., Prestige, None, None;
+    CatLadies = 547, "Cat ladies", 9e9, Prestige, None, None;
+    MilkhelpLactoseIntoleranceReliefTablets = 548, "Milkhelp® lactose intolerance relief tablets", 6e10, Prestige, None, None;
+    AuraGloves = 549, "Aura gloves", 555555555., Prestige, None, None;
+    LuminousGloves = 550, "Luminous gloves", 55555555555., Prestige, None, None;
+    DragonFang = 551, "Dragon fang", 1e6, Normal, None, None;
+    DragonScale = 552, "Dragon scale", 1e6, Normal, None, None;
+    DragonClaw = 553, "Dragon claw", 1e6, Normal, None, None;
+    DragonTeddyBear = 554, "Dragon teddy bear", 1e6, Normal, None, None;
+    CustardCreams = 555, "Custard creams", 1e35, Cookie, None, None;
+    BourbonBiscuits = 556, "Bourbon biscuits", 1e35, Cookie, None, None;
+    MiniCookies = 557, "Mini-cookies", 1e35, Cookie, None, None;
+    WhoopiePies = 558, "Whoopie pies", 1e35, Cookie, None, None;
+    CaramelWaferBiscuits = 559, "Caramel wafer biscuits", 1e35, Cookie, None, None;
+    ChocolateChipMochaCookies = 560, "Chocolate chip mocha cookies", 1e35, Cookie, None, None;
+    EarlGreyCookies = 561, "Earl Grey cookies", 1e35, Cookie, None, None;
+    CornSyrupCookies = 562, "Corn syrup cookies", 1e35, Cookie, None, None;
+    IceboxCookies = 563, "Icebox cookies", 1e35, Cookie, None, None;
+    GrahamCrackers = 564, "Graham crackers", 1e35, Cookie, None, None;
+    Hardtack = 565, "Hardtack", 1e35, Cookie, None, None;
+    CornflakeCookies = 566, "Cornflake cookies", 1e35, Cookie, None, None;
+    TofuCookies = 567, "Tofu cookies", 1e35, Cookie, None, None;
+    GlutenFreeCookies = 568, "Gluten-free cookies", 1e35, Cookie, None, None;
+    RussianBreadCookies = 569, "Russian bread cookies", 1e35, Cookie, None, None;
+    Lebkuchen = 570, "Lebkuchen", 1e35, Cookie, None, None;
+    AachenerPrinten = 571, "Aachener Printen", 1e35, Cookie, None, None;
+    Canistrelli = 572, "Canistrelli", 1e35, Cookie, None, None;
+    NiceBiscuits = 573, "Nice biscuits", 1e35, Cookie, None, None;
+    FrenchPureButterCookies = 574, "French pure butter cookies", 1e35, Cookie, None, None;
+    PetitBeurre = 575, "Petit beurre", 1e35, Cookie, None, None;
+    NanaimoBars = 576, "Nanaimo bars", 1e35, Cookie, None, None;
+    BergerCookies = 577, "Berger cookies", 1e35, Cookie, None, None;
+    Chinsuko = 578, "Chinsuko", 1e35, Cookie, None, None;
+    PandaKoalaBiscuits = 579, "Panda koala biscuits", 1e35, Cookie, None, None;
+    PutriSalju = 580, "Putri salju", 1e35, Cookie, None, None;
+    MilkCookies = 581, "Milk cookies", 1e35, Cookie, None, None;
+    GoodManners = 582, "Good manners", 5e25, Normal, Some(Grandma), Some(11);
+    Lindworms = 583, "Lindworms", 5.5e26, Normal, Some(Farm), Some(11);
+    BoreAgain = 584, "Bore again", 6e27, Normal, Some(Mine), Some(11);
+    VolunteerInterns = 585, "\"Volunteer\" interns", 6.5e28, Normal, Some(Factory), Some(11);
+    RulesOfAcquisition = 586, "Rules of acquisition", 7e29, Normal, Some(Bank), Some(11);
+    WarOfTheGods = 587, "War of the gods", 1e31, Normal, Some(Temple), Some(11);
+    Electricity = 588, "Electricity", 1.65e32, Normal, Some(WizardTower), Some(11);
+    UniversalAlphabet = 589, "Universal alphabet", 2.55e33, Normal, Some(Shipment), Some(11);
+    PublicBetterment = 590, "Public betterment", 3.75e34, Normal, Some(AlchemyLab), Some(11);
+    EmbeddedMicroportals = 591, "Embedded microportals", 5e35, Normal, Some(Portal), Some(11);
+    Nostalgia = 592, "Nostalgia", 7e36, Normal, Some(TimeMachine), Some(11);
+    TheDefiniteMolecule = 593, "The definite molecule", 8.5e37, Normal, Some(AntimatterCondenser), Some(11);
+    LightCaptureMeasures = 594, "Light capture measures", 1.05e39, Normal, Some(Prism), Some(11);
+    N0SidedDice = 595, "0-sided dice", 1.3e40, Normal, Some(Chancemaker), Some(11);
+    TheSetOfAllSets = 596, "The set of all sets", 1.5499999999999999e41, Normal, Some(FractalEngine), Some(11);
+    TheJavaScriptConsoleForDummies = 597, "The JavaScript console for dummies", 7.1e20, Normal, Some(JavascriptConsole), Some(1);
+    N64bitArrays = 598, "64bit arrays", 3.55e21, Normal, Some(JavascriptConsole), Some(2);
+    StackOverflow = 599, "Stack overflow", 3.55e22, Normal, Some(JavascriptConsole), Some(3);
+    EnterpriseCompiler = 600, "Enterprise compiler", 3.55e24, Normal, Some(JavascriptConsole), Some(4);
+    SyntacticSugar = 601, "Syntactic sugar", 3.55e26, Normal, Some(JavascriptConsole), Some(5);
+    ANiceCupOfCoffee = 602, "A nice cup of coffee", 3.55e28, Normal, Some(JavascriptConsole), Some(6);
+    JustInTimeBaking = 603, "Just-in-time baking", 3.55e31, Normal, Some(JavascriptConsole), Some(7);
+    Cookies = 604, "cookies++", 3.55e34, Normal, Some(JavascriptConsole), Some(8);
+    SoftwareUpdates = 605, "Software updates", 3.55e37, Normal, Some(JavascriptConsole), Some(9);
+    GameLoop = 606, "Game.Loop", 3.55e40, Normal, Some(JavascriptConsole), Some(10);
+    Eval = 607, "eval()", 3.55e43, Normal, Some(JavascriptConsole), Some(11);
+    BinaryGrandmas = 608, "Binary grandmas", 3.55e21, Normal, Some(Grandma), None;
+    ScriptGrannies = 609, "Script grannies", 1.42e25, Normal, Some(JavascriptConsole), None;
+    TombolaComputing = 610, "Tombola computing", 1.42052e31, Normal, Some(JavascriptConsole), None;
+    UndecillionFingers = 611, "Undecillion fingers", 1e31, Normal, Some(Cursor), None;
+    PlasmarbleMouse = 612, "Plasmarble mouse", 5e26, Normal, None, Some(12);
+    KittenAnalysts = 613, "Kitten analysts", 9e41, Kitten, None, None;
+    Fortune017 = 614, "Fortune #017", 7.1e31, Normal, None, None;
+    PurePitchBlackChocolateButterBiscuit = 615, "Pure pitch-black chocolate butter biscuit", 1e36, Cookie, None, None;
+    PrismHeartBiscuits = 616, "Prism heart biscuits", 1e6, Cookie, None, None;
+    Kruidnoten = 617, "Kruidnoten", 1e37, Cookie, None, None;
+    MarieBiscuits = 618, "Marie biscuits", 1e37, Cookie, None, None;
+    MeringueCookies = 619, "Meringue cookies", 1e37, Cookie, None, None;
+    YogurtCookies = 620, "Yogurt cookies", 1e37, Cookie, None, None;
+    ThumbprintCookies = 621, "Thumbprint cookies", 1e37, Cookie, None, None;
+    Pizzelle = 622, "Pizzelle", 1e37, Cookie, None, None;
+    GenerationDegeneration = 623, "Generation degeneration", 5.0000000000000005e28, Normal, Some(Grandma), Some(12);
+    GlobalSeedVault = 624, "Global seed vault", 5.5e29, Normal, Some(Farm), Some(12);
+    AirMining = 625, "Air mining", 6e30, Normal, Some(Mine), Some(12);
+    BehavioralReframing = 626, "Behavioral reframing", 6.5e31, Normal, Some(Factory), Some(12);
+    AltruisticLoop = 627, "Altruistic loop", 7e32, Normal, Some(Bank), Some(12);
+    ANovelIdea = 628, "A novel idea", 1.0000000000000001e34, Normal, Some(Temple), Some(12);
+    SpellingBees = 629, "Spelling bees", 1.65e35, Normal, Some(WizardTower), Some(12);
+    ToroidUniverse = 630, "Toroid universe", 2.5500000000000002e36, Normal, Some(Shipment), Some(12);
+    HermeticReconciliation = 631, "Hermetic reconciliation", 3.75e37, Normal, Some(AlchemyLab), Some(12);
+    HisAdvent = 632, "His advent", 5e38, Normal, Some(Portal), Some(12);
+    SplitSeconds = 633, "Split seconds", 7e39, Normal, Some(TimeMachine), Some(12);
+    FlavorItself = 634, "Flavor itself", 8.5e40, Normal, Some(AntimatterCondenser), Some(12);
+    LightSpeedLimit = 635, "Light speed limit", 1.05e42, Normal, Some(Prism), Some(12);
+    ATouchOfDeterminism = 636, "A touch of determinism", 1.3e43, Normal, Some(Chancemaker), Some(12);
+    ThisUpgrade = 637, "This upgrade", 1.55e44, Normal, Some(FractalEngine), Some(12);
+    YourBiggestFans = 638, "Your biggest fans", 3.55e46, Normal, Some(JavascriptConsole), Some(12);
+    MiraculiteMouse = 639, "Miraculite mouse", 5e28, Normal, None, Some(13);
+    KittenExecutives = 640, "Kitten executives", 9e44, Kitten, None, None;
+    Fortune018 = 641, "Fortune #018", 1e15, Normal, None, None;
+    CosmicChocolateButterBiscuit = 642, "Cosmic chocolate butter biscuit", 1e39, Cookie, None, None;
+    ZillaWafers = 643, "Zilla wafers", 1e40, Cookie, None, None;
+    DimDams = 644, "Dim Dams", 1e40, Cookie, None, None;
+    Candy = 645, "Candy", 1e40, Cookie, None, None;
+    PeanutButterBlossoms = 646, "Peanut butter blossoms", 1e40, Cookie, None, None;
+    NoBakeCookies = 647, "No-bake cookies", 1e40, Cookie, None, None;
+    Florentines = 648, "Florentines", 1e40, Cookie, None, None;
+    ChocolateCrinkles = 649, "Chocolate crinkles", 1e40, Cookie, None, None;
+    MapleCookies = 650, "Maple cookies", 1e40, Cookie, None, None;
+    ManifestDestiny = 651, "Manifest destiny", 1.2e23, Normal, Some(Idleverse), Some(1);
+    TheMultiverseInANutshell = 652, "The multiverse in a nutshell", 6e23, Normal, Some(Idleverse), Some(2);
+    AllConversion = 653, "All-conversion", 6e24, Normal, Some(Idleverse), Some(3);
+    MultiverseAgents = 654, "Multiverse agents", 6e26, Normal, Some(Idleverse), Some(4);
+    EscapePlan = 655, "Escape plan", 6e28, Normal, Some(Idleverse), Some(5);
+    GameDesign = 656, "Game design", 6e30, Normal, Some(Idleverse), Some(6);
+    SandboxUniverses = 657, "Sandbox universes", 6e33, Normal, Some(Idleverse), Some(7);
+    MultiverseWars = 658, "Multiverse wars", 6e36, Normal, Some(Idleverse), Some(8);
+    MobilePorts = 659, "Mobile ports", 6e39, Normal, Some(Idleverse), Some(9);
+    EncapsulatedRealities = 660, "Encapsulated realities", 6e42, Normal, Some(Idleverse), Some(10);
+    ExtrinsicClicking = 661, "Extrinsic clicking", 6e45, Normal, Some(Idleverse), Some(11);
+    UniversalIdling = 662, "Universal idling", 6e48, Normal, Some(Idleverse), Some(12);
+    BreakTheFifthWall = 663, "Break the fifth wall", 6e51, Normal, Some(Idleverse), Some(13);
+    Visits = 664, "Visits", 5e31, Normal, Some(Grandma), Some(13);
+    ReverseVeganism = 665, "Reverse-veganism", 5.5e32, Normal, Some(Farm), Some(13);
+    CaramelAlloys = 666, "Caramel alloys", 6e33, Normal, Some(Mine), Some(13);
+    TheInfinityEngine = 667, "The infinity engine", 6.5e34, Normal, Some(Factory), Some(13);
+    DiminishingTaxReturns = 668, "Diminishing tax returns", 7e35, Normal, Some(Bank), Some(13);
+    Apparitions = 669, "Apparitions", 1.0000000000000001e37, Normal, Some(Temple), Some(13);
+    WizardBasements = 670, "Wizard basements", 1.65e38, Normal, Some(WizardTower), Some(13);
+    PrimeDirective = 671, "Prime directive", 2.55e39, Normal, Some(Shipment), Some(13);
+    ChromaticCycling = 672, "Chromatic cycling", 3.7500000000000003e40, Normal, Some(AlchemyLab), Some(13);
+    DomesticRifts = 673, "Domestic rifts", 5e41, Normal, Some(Portal), Some(13);
+    PatienceAbolished = 674, "Patience abolished", 7e42, Normal, Some(TimeMachine), Some(13);
+    DeliciousPull = 675, "Delicious pull", 8.500000000000001e43, Normal, Some(AntimatterCondenser), Some(13);
+    OccamsLaser = 676, "Occam's laser", 1.05e45, Normal, Some(Prism), Some(13);
+    OnAStreak = 677, "On a streak", 1.3e46, Normal, Some(Chancemaker), Some(13);
+    ABox = 678, "A box", 1.55e47, Normal, Some(FractalEngine), Some(13);
+    HackerShades = 679, "Hacker shades", 3.55e49, Normal, Some(JavascriptConsole), Some(13);
+    AlternateGrandmas = 680, "Alternate grandmas", 6e23, Normal, Some(Grandma), None;
+    PerforatedMilleFeuilleCosmos = 681, "Perforated mille-feuille cosmos", 2.4000000002e27, Normal, Some(Idleverse), None;
+    InfraversesAndSuperverses = 682, "Infraverses and superverses", 2.400062e33, Normal, Some(Idleverse), None;
+    Fortune019 = 683, "Fortune #019", 1.2e34, Normal, None, None;
+    KittenWages = 684, "Kitten wages", 9e9, Prestige, None, None;
+    DistinguishedWallpaperAssortment = 685, "Distinguished wallpaper assortment", 999999., Prestige, None, None;
+    ButterBiscuitWithButter = 686, "Butter biscuit (with butter)", 1e42, Cookie, None, None;
+    PersianRiceCookies = 687, "Persian rice cookies", 1e43, Cookie, None, None;
+    NorwegianCookies = 688, "Norwegian cookies", 1e43, Cookie, None, None;
+    CrispyRiceCookies = 689, "Crispy rice cookies", 1e43, Cookie, None, None;
+    UbeCookies = 690, "Ube cookies", 1e43, Cookie, None, None;
+    ButterscotchCookies = 691, "Butterscotch cookies", 1e43, Cookie, None, None;
+    Speculaas = 692, "Speculaas", 1e43, Cookie, None, None;
+    ChocolateOatmealCookies = 693, "Chocolate oatmeal cookies", 1e43, Cookie, None, None;
+    MolassesCookies = 694, "Molasses cookies", 1e43, Cookie, None, None;
+    Biscotti = 695, "Biscotti", 1e43, Cookie, None, None;
+    WaffleCookies = 696, "Waffle cookies", 1e43, Cookie, None, None;
+    AlmondCookies = 697, "Almond cookies", 1e43, Cookie, None, None;
+    HazelnutCookies = 698, "Hazelnut cookies", 1e43, Cookie, None, None;
+    WalnutCookies = 699, "Walnut cookies", 1e43, Cookie, None, None;
+    CashewCookies = 700, "Cashew cookies", 1e43, Cookie, None, None;
+    MilkChocolateCookies = 701, "Milk chocolate cookies", 1e43, Cookie, None, None;
+    PrincipledNeuralShackles = 702, "Principled neural shackles", 1.9e25, Normal, Some(CortexBaker), Some(1);
+    Obey = 703, "Obey", 9.5e25, Normal, Some(CortexBaker), Some(2);
+    ASprinkleOfIrrationality = 704, "A sprinkle of irrationality", 9.5e26, Normal, Some(CortexBaker), Some(3);
+    FrontAndBackHemispheres = 705, "Front and back hemispheres", 9.500000000000001e28, Normal, Some(CortexBaker), Some(4);
+    NeuralNetworking = 706, "Neural networking", 9.5e30, Normal, Some(CortexBaker), Some(5);
+    CosmicBrainstorms = 707, "Cosmic brainstorms", 9.5e32, Normal, Some(CortexBaker), Some(6);
+    Megatherapy = 708, "Megatherapy", 9.5e35, Normal, Some(CortexBaker), Some(7);
+    SynapticLubricant = 709, "Synaptic lubricant", 9.500000000000001e38, Normal, Some(CortexBaker), Some(8);
+    Psychokinesis = 710, "Psychokinesis", 9.500000000000001e41, Normal, Some(CortexBaker), Some(9);
+    Spines = 711, "Spines", 9.5e44, Normal, Some(CortexBaker), Some(10);
+    Neuraforming = 712, "Neuraforming", 9.5e47, Normal, Some(CortexBaker), Some(11);
+    EpistemologicalTrickery = 713, "Epistemological trickery", 9.500000000000001e50, Normal, Some(CortexBaker), Some(12);
+    EveryPossibleIdea = 714, "Every possible idea", 9.5e53, Normal, Some(CortexBaker), Some(13);
+    Kaizen = 715, "Kaizen", 9.5e56, Normal, Some(CortexBaker), Some(14);
+    KitchenCabinets = 716, "Kitchen cabinets", 5e34, Normal, Some(Grandma), Some(14);
+    CookieMulch = 717, "Cookie mulch", 5.5e35, Normal, Some(Farm), Some(14);
+    DeliciousMineralogy = 718, "Delicious mineralogy", 6e36, Normal, Some(Mine), Some(14);
+    NDimensionalAssemblyLines = 719, "N-dimensional assembly lines", 6.5e37, Normal, Some(Factory), Some(14);
+    CookiePoints = 720, "Cookie Points", 7e38, Normal, Some(Bank), Some(14);
+    Negatheism = 721, "Negatheism", 9.999999999999999e39, Normal, Some(Temple), Some(14);
+    MagicalRealism = 722, "Magical realism", 1.6499999999999998e41, Normal, Some(WizardTower), Some(14);
+    CosmicForegroundRadiation = 723, "Cosmic foreground radiation", 2.55e42, Normal, Some(Shipment), Some(14);
+    ArcanizedGlassware = 724, "Arcanized glassware", 3.75e43, Normal, Some(AlchemyLab), Some(14);
+    PortalGuns = 725, "Portal guns", 5e44, Normal, Some(Portal), Some(14);
+    TimeproofUpgrades = 726, "Timeproof upgrades", 6.999999999999999e45, Normal, Some(TimeMachine), Some(14);
+    EmployeeMinification = 727, "Employee minification", 8.5e46, Normal, Some(AntimatterCondenser), Some(14);
+    HyperblackPaint = 728, "Hyperblack paint", 1.05e48, Normal, Some(Prism), Some(14);
+    SilverLiningMaximization = 729, "Silver lining maximization", 1.3e49, Normal, Some(Chancemaker), Some(14);
+    MultiscaleMicroscopy = 730, "Multiscale microscopy", 1.55e50, Normal, Some(FractalEngine), Some(14);
+    PHPContainmentVats = 731, "PHP containment vats", 3.55e52, Normal, Some(JavascriptConsole), Some(14);
+    OppositeUniverse = 732, "Opposite universe", 6e54, Normal, Some(Idleverse), Some(14);
+    BrainyGrandmas = 733, "Brainy grandmas", 9.5e25, Normal, Some(Grandma), None;
+    ThoughtsPrayers = 734, "Thoughts & prayers", 3.8000000000000004e29, Normal, Some(CortexBaker), None;
+    FertileMinds = 735, "Fertile minds", 3.8e35, Normal, Some(CortexBaker), None;
+    AethericeMouse = 736, "Aetherice mouse", 5e30, Normal, None, Some(14);
+    KittenAdmins = 737, "Kitten admins", 9e47, Kitten, None, None;
+    Fortune020 = 738, "Fortune #020", 1.9e36, Normal, None, None;
+    EverybutterBiscuit = 739, "Everybutter biscuit", 1e48, Cookie, None, None;
+    Jukebox = 740, "Jukebox", 0., Toggle, None, None;
+    SoundTest = 741, "Sound test", 7., Debug, None, None;
+    TurboChargedSoil = 742, "Turbo-charged soil", 7., Debug, None, None;
+    GranolaCookies = 743, "Granola cookies", 1e49, Cookie, None, None;
+    RicottaCookies = 744, "Ricotta cookies", 1e49, Cookie, None, None;
+    RozeKoeken = 745, "Roze koeken", 1e49, Cookie, None, None;
+    PeanutButterCupCookies = 746, "Peanut butter cup cookies", 1e49, Cookie, None, None;
+    SesameCookies = 747, "Sesame cookies", 1e49, Cookie, None, None;
+    Taiyaki = 748, "Taiyaki", 1e49, Cookie, None, None;
+    Vanillekipferl = 749, "Vanillekipferl", 1e49, Cookie, None, None;
+    BattenbergBiscuits = 750, "Battenberg biscuits", 1e49, Cookie, None, None;
+    RosetteCookies = 751, "Rosette cookies", 1e49, Cookie, None, None;
+    Gangmakers = 752, "Gangmakers", 1e49, Cookie, None, None;
+    WelshCookies = 753, "Welsh cookies", 1e49, Cookie, None, None;
+    RaspberryCheesecakeCookies = 754, "Raspberry cheesecake cookies", 1e49, Cookie, None, None;
+    CloningVats = 755, "Cloning vats", 5.4e27, Normal, Some(You), Some(1);
+    EnergizedNutrients = 756, "Energized nutrients", 2.7e28, Normal, Some(You), Some(2);
+    StuntDoubles = 757, "Stunt doubles", 2.7e29, Normal, Some(You), Some(3);
+    CloneRecyclingPlant = 758, "Clone recycling plant", 2.7e31, Normal, Some(You), Some(4);
+    FreeRangeClones = 759, "Free-range clones", 2.7e33, Normal, Some(You), Some(5);
+    GeneticTailoring = 760, "Genetic tailoring", 2.7e35, Normal, Some(You), Some(6);
+    PowerInDiversity = 761, "Power in diversity", 2.7e38, Normal, Some(You), Some(7);
+    SelfBetterment = 762, "Self-betterment", 2.7e41, Normal, Some(You), Some(8);
+    SourceControl = 763, "Source control", 2.7e44, Normal, Some(You), Some(9);
+    UnitedWorkforce = 764, "United workforce", 2.7e47, Normal, Some(You), Some(10);
+    SafetyPatrols = 765, "Safety patrols", 2.7e50, Normal, Some(You), Some(11);
+    CloneRights = 766, "Clone rights", 2.7e53, Normal, Some(You), Some(12);
+    OneBigFamily = 767, "One big family", 2.7e56, Normal, Some(You), Some(13);
+    FineTunedBodyPlans = 768, "Fine-tuned body plans", 2.6999999999999997e59, Normal, Some(You), Some(14);
+    ReadingYourClonesBedtimeStories = 769, "Reading your clones bedtime stories", 2.7e62, Normal, Some(You), Some(15);
+    FoamTippedCanes = 770, "Foam-tipped canes", 5e37, Normal, Some(Grandma), Some(15);
+    SelfDrivingTractors = 771, "Self-driving tractors", 5.5e38, Normal, Some(Farm), Some(15);
+    MineshaftSupports = 772, "Mineshaft supports", 6e39, Normal, Some(Mine), Some(15);
+    UniversalAutomation = 773, "Universal automation", 6.5e40, Normal, Some(Factory), Some(15);
+    TheBigShortcake = 774, "The big shortcake", 7e41, Normal, Some(Bank), Some(15);
+    TempleTraps = 775, "Temple traps", 1e43, Normal, Some(Temple), Some(15);
+    Polymorphism = 776, "Polymorphism", 1.65e44, Normal, Some(WizardTower), Some(15);
+    AtYourDoorstepIn30MinutesOrYourMoneyBack = 777, "At your doorstep in 30 minutes or your money back", 2.5500000000000002e45, Normal, Some(Shipment), Some(15);
+    TheDoseMakesThePoison = 778, "The dose makes the poison", 3.75e46, Normal, Some(AlchemyLab), Some(15);
+    AWayHome = 779, "A way home", 5e47, Normal, Some(Portal), Some(15);
+    RectifyingAMistake = 780, "Rectifying a mistake", 7.000000000000001e48, Normal, Some(TimeMachine), Some(15);
+    CandiedAtoms = 781, "Candied atoms", 8.500000000000001e49, Normal, Some(AntimatterCondenser), Some(15);
+    LabGogglesButLikeCoolShades = 782, "Lab goggles but like cool shades", 1.05e51, Normal, Some(Prism), Some(15);
+    GamblersFallacyFallacy = 783, "Gambler's fallacy fallacy", 1.3e52, Normal, Some(Chancemaker), Some(15);
+    FractalFractal = 784, "Fractal fractal", 1.55e53, Normal, Some(FractalEngine), Some(15);
+    SimulationFailsafes = 785, "Simulation failsafes", 3.55e55, Normal, Some(JavascriptConsole), Some(15);
+    TheOtherRoutesToRome = 786, "The other routes to Rome", 6e57, Normal, Some(Idleverse), Some(15);
+    MaybeAChanceInHellActually = 787, "Maybe a chance in hell, actually", 9.5e59, Normal, Some(CortexBaker), Some(15);
+    CloneGrandmas = 788, "Clone grandmas", 2.7e28, Normal, Some(Grandma), None;
+    AcceleratedDevelopment = 789, "Accelerated development", 1.0838e32, Normal, Some(You), None;
+    PeerReview = 790, "Peer review", 1.080000142e38, Normal, Some(You), None;
+    OmniplastMouse = 791, "Omniplast mouse", 5e32, Normal, None, Some(15);
+    DuodecillionFingers = 792, "Duodecillion fingers", 1e34, Normal, Some(Cursor), None;
+    KittenStrategists = 793, "Kitten strategists", 9e50, Kitten, None, None;
+    Fortune021 = 794, "Fortune #021", 5.4e38, Normal, None, None;
+    PersonalBiscuit = 795, "Personal biscuit", 1e54, Cookie, None, None;
+    WrappingPaper = 796, "Wrapping paper", 999999999999., Prestige, None, None;
+    UnshackledCursors = 797, "Unshackled cursors", 1e15, Prestige, None, None;
+    UnshackledGrandmas = 798, "Unshackled grandmas", 1e15, Prestige, None, None;
+    UnshackledFarms = 799, "Unshackled farms", 1e15, Prestige, None, None;
+    UnshackledMines = 800, "Unshackled mines", 1e15, Prestige, None, None;
+    UnshackledFactories = 801, "Unshackled factories", 1e15, Prestige, None, None;
+    UnshackledBanks = 802, "Unshackled banks", 1e15, Prestige, None, None;
+    UnshackledTemples = 803, "Unshackled temples", 1e15, Prestige, None, None;
+    UnshackledWizardTowers = 804, "Unshackled wizard towers", 1e15, Prestige, None, None;
+    UnshackledShipments = 805, "Unshackled shipments", 1e15, Prestige, None, None;
+    UnshackledAlchemyLabs = 806, "Unshackled alchemy labs", 1e15, Prestige, None, None;
+    UnshackledPortals = 807, "Unshackled portals", 1e15, Prestige, None, None;
+    UnshackledTimeMachines = 808, "Unshackled time machines", 1e15, Prestige, None, None;
+    UnshackledAntimatterCondensers = 809, "Unshackled antimatter condensers", 1e15, Prestige, None, None;
+    UnshackledPrisms = 810, "Unshackled prisms", 1e15, Prestige, None, None;
+    UnshackledChancemakers = 811, "Unshackled chancemakers", 1e15, Prestige, None, None;
+    UnshackledFractalEngines = 812, "Unshackled fractal engines", 1e15, Prestige, None, None;
+    UnshackledJavascriptConsoles = 813, "Unshackled javascript consoles", 1e15, Prestige, None, None;
+    UnshackledIdleverses = 814, "Unshackled idleverses", 1e15, Prestige, None, None;
+    UnshackledCortexBakers = 815, "Unshackled cortex bakers", 1e15, Prestige, None, None;
+    UnshackledYou = 816, "Unshackled You", 1e15, Prestige, None, None;
+    UnshackledFlavor = 817, "Unshackled flavor", 1e15, Prestige, None, None;
+    UnshackledBerrylium = 818, "Unshackled berrylium", 1e15, Prestige, None, None;
+    UnshackledBlueberrylium = 819, "Unshackled blueberrylium", 1e15, Prestige, None, None;
+    UnshackledChalcedhoney = 820, "Unshackled chalcedhoney", 1e15, Prestige, None, None;
+    UnshackledButtergold = 821, "Unshackled buttergold", 1e15, Prestige, None, None;
+    UnshackledSugarmuck = 822, "Unshackled sugarmuck", 1e15, Prestige, None, None;
+    UnshackledJetmint = 823, "Unshackled jetmint", 1e15, Prestige, None, None;
+    UnshackledCherrysilver = 824, "Unshackled cherrysilver", 1e15, Prestige, None, None;
+    UnshackledHazelrald = 825, "Unshackled hazelrald", 1e15, Prestige, None, None;
+    UnshackledMooncandy = 826, "Unshackled mooncandy", 1e15, Prestige, None, None;
+    UnshackledAstrofudge = 827, "Unshackled astrofudge", 1e15, Prestige, None, None;
+    UnshackledAlabascream = 828, "Unshackled alabascream", 1e15, Prestige, None, None;
+    UnshackledIridyum = 829, "Unshackled iridyum", 1e15, Prestige, None, None;
+    UnshackledGlucosmium = 830, "Unshackled glucosmium", 1e15, Prestige, None, None;
+    UnshackledGlimmeringue = 831, "Unshackled glimmeringue", 1e15, Prestige, None, None;
+    Bokkenpootjes = 832, "Bokkenpootjes", 1e55, Cookie, None, None;
+    FatRascals = 833, "Fat rascals", 1e55, Cookie, None, None;
+    IschlerCookies = 834, "Ischler cookies", 1e55, Cookie, None, None;
+    MatchaCookies = 835, "Matcha cookies", 1e55, Cookie, None, None;
+    Havreflarn = 836, "Havreflarn", 1e55, Cookie, None, None;
+    Alfajores = 837, "Alfajores", 1e55, Cookie, None, None;
+    Gaufrettes = 838, "Gaufrettes", 1e55, Cookie, None, None;
+    KolachyCookies = 839, "Kolachy cookies", 1e55, Cookie, None, None;
+    GommaCookies = 840, "Gomma cookies", 1e55, Cookie, None, None;
+    VeganCookies = 841, "Vegan cookies", 1e55, Cookie, None, None;
+    Coyotas = 842, "Coyotas", 1e55, Cookie, None, None;
+    FrostedSugarCookies = 843, "Frosted sugar cookies", 1e55, Cookie, None, None;
+    MarshmallowSandwichCookies = 844, "Marshmallow sandwich cookies", 1e55, Cookie, None, None;
+    CinnamonRolls = 845, "Cinnamon rolls", 1e56, Cookie, None, None;
+    Shortcakes = 846, "Shortcakes", 1e56, Cookie, None, None;
+    Castellas = 847, "Castellas", 1e56, Cookie, None, None;
+    Pfeffernusse = 848, "Pfeffernüsse", 1e56, Cookie, None, None;
+    Kokosmakronen = 849, "Kokosmakronen", 1e56, Cookie, None, None;
+    ToffeeCookies = 850, "Toffee cookies", 1e56, Cookie, None, None;
+    LinzerCookies = 851, "Linzer cookies", 1e56, Cookie, None, None;
+    JammieDodgers = 852, "Jammie dodgers", 1e56, Cookie, None, None;
+    Hobnobs = 853, "Hobnobs", 1e56, Cookie, None, None;
+    SpritzCookies = 854, "Spritz cookies", 1e56, Cookie, None, None;
+    BrandySnaps = 855, "Brandy snaps", 1e56, Cookie, None, None;
+    Baklava = 856, "Baklava", 1e56, Cookie, None, None;
+    SnowballCookies = 857, "Snowball cookies", 1e56, Cookie, None, None;
+    BearClaws = 858, "Bear claws", 1e56, Cookie, None, None;
+    BlackAndWhiteCookies = 859, "Black and white cookies", 1e56, Cookie, None, None;
+    Cursedor = 860, "Cursedor", 1e14, Prestige, None, None;
+    DragonOrbs = 861, "Dragon orbs", 1e14, Prestige, None, None;
+    LemonBars = 862, "Lemon bars", 1e57, Cookie, None, None;
+    ChewyCookies = 863, "Chewy cookies", 1e57, Cookie, None, None;
+    CrackleCookies = 864, "Crackle cookies", 1e57, Cookie, None, None;
+    PretzelCookies = 865, "Pretzel cookies", 1e57, Cookie, None, None;
+    CherryCookies = 866, "Cherry cookies", 1e57, Cookie, None, None;
+    PistachioCookies = 867, "Pistachio cookies", 1e57, Cookie, None, None;
+    SourCreamCookies = 868, "Sour cream cookies", 1e57, Cookie, None, None;
+    Pignoli = 869, "Pignoli", 1e57, Cookie, None, None;
+    Amaretti = 870, "Amaretti", 1e57, Cookie, None, None;
+    HornCookies = 871, "Horn cookies", 1e57, Cookie, None, None;
+    SableCookies = 872, "Sable cookies", 1e57, Cookie, None, None;
+    TahiniCookies = 873, "Tahini cookies", 1e57, Cookie, None, None;
+    OrangeCookies = 874, "Orange cookies", 1e57, Cookie, None, None;
+}
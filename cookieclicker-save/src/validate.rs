@@ -0,0 +1,305 @@
+use crate::version;
+use crate::{Building, BuildingDataEntry, GameVersion, Garden, Save};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum Severity {
+    /// Unusual, but the game copes with it.
+    Warning,
+    /// The game would misbehave or discard data.
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum Violation {
+    /// A field that must be a finite, non-negative amount is not.
+    InvalidAmount {
+        field: &'static str,
+        value: f64,
+    },
+    OwnedExceedsHighest {
+        building: Building,
+        amount_owned: u64,
+        highest_amount: u64,
+    },
+    UpgradesLength {
+        expected: usize,
+        actual: usize,
+    },
+    AchievementsLength {
+        expected: usize,
+        actual: usize,
+    },
+    /// The vault refers to an upgrade that does not exist.
+    UnknownVaultUpgrade {
+        id: usize,
+    },
+    /// More heavenly chips were spent than the prestige level ever granted.
+    HeavenlyChipsOverspent {
+        spent: f64,
+        prestige: f64,
+    },
+    GardenSize {
+        expected: usize,
+        actual: usize,
+    },
+    UnknownPlant {
+        x: usize,
+        y: usize,
+        id: usize,
+    },
+    /// Plants die once their age reaches 100.
+    PlantTooOld {
+        x: usize,
+        y: usize,
+        age: u64,
+    },
+    /// A spirit is slotted more than once.
+    DuplicateSpirit {
+        slot: usize,
+    },
+    /// A field of `section` that is `missing` although `version` has it, or present although
+    /// it was added later.
+    LayoutMismatch {
+        section: &'static str,
+        field: &'static str,
+        version: GameVersion,
+        missing: bool,
+    },
+}
+
+impl Violation {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::OwnedExceedsHighest { .. }
+            | Self::PlantTooOld { .. }
+            | Self::LayoutMismatch { .. } => Severity::Warning,
+            Self::InvalidAmount { .. }
+            | Self::UpgradesLength { .. }
+            | Self::AchievementsLength { .. }
+            | Self::UnknownVaultUpgrade { .. }
+            | Self::HeavenlyChipsOverspent { .. }
+            | Self::GardenSize { .. }
+            | Self::UnknownPlant { .. }
+            | Self::DuplicateSpirit { .. } => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAmount { field, value } => write!(f, "{field} is {value}"),
+            Self::OwnedExceedsHighest {
+                building,
+                amount_owned,
+                highest_amount,
+            } => write!(
+                f,
+                "{} owned: {amount_owned}, more than the highest amount {highest_amount}",
+                building.name(),
+            ),
+            Self::UpgradesLength { expected, actual } => {
+                write!(f, "{actual} upgrades, expected {expected}")
+            }
+            Self::AchievementsLength { expected, actual } => {
+                write!(f, "{actual} achievements, expected {expected}")
+            }
+            Self::UnknownVaultUpgrade { id } => write!(f, "unknown upgrade {id} in the vault"),
+            Self::HeavenlyChipsOverspent { spent, prestige } => write!(
+                f,
+                "{spent} heavenly chips spent, more than the prestige level {prestige}",
+            ),
+            Self::GardenSize { expected, actual } => {
+                write!(f, "{actual} garden tiles, expected {expected}")
+            }
+            Self::UnknownPlant { x, y, id } => write!(f, "unknown plant {id} at ({x}, {y})"),
+            Self::PlantTooOld { x, y, age } => write!(f, "plant at ({x}, {y}) is {age} old"),
+            Self::DuplicateSpirit { slot } => write!(f, "spirit in slot {slot} is slotted twice"),
+            Self::LayoutMismatch {
+                section,
+                field,
+                version,
+                missing,
+            } => {
+                let state = if *missing { "missing" } else { "unexpected" };
+                write!(f, "{section}.{field}: {state} in version {version}")
+            }
+        }
+    }
+}
+
+pub(crate) fn validate(value: &Save) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    let misc = &value.miscellaneous_game_data;
+    for (field, amount) in [
+        ("cookies_in_bank", misc.cookies_in_bank),
+        ("cookies_baked", misc.cookies_baked),
+        ("hand_made_cookies", misc.hand_made_cookies),
+        (
+            "cookies_forfeited_by_ascending",
+            misc.cookies_forfeited_by_ascending,
+        ),
+        ("prestive_level", misc.prestive_level),
+        ("heavenly_chips", misc.heavenly_chips),
+        ("heavenly_chips_spent", misc.heavenly_chips_spent),
+    ] {
+        if !amount.is_finite() || amount < 0. {
+            violations.push(Violation::InvalidAmount {
+                field,
+                value: amount,
+            });
+        }
+    }
+    // Late saves reach prestige levels where `f64` sums round, so chips left and spent are
+    // not required to add up exactly.
+    if misc.heavenly_chips_spent > misc.prestive_level {
+        violations.push(Violation::HeavenlyChipsOverspent {
+            spent: misc.heavenly_chips_spent,
+            prestige: misc.prestive_level,
+        });
+    }
+
+    for (building, entry) in value.building_data.entries() {
+        let BuildingDataEntry {
+            amount_owned,
+            highest_amount,
+            ..
+        } = entry;
+        if amount_owned > highest_amount {
+            violations.push(Violation::OwnedExceedsHighest {
+                building,
+                amount_owned,
+                highest_amount,
+            });
+        }
+    }
+
+    if let Some((upgrades, achievements)) = version::list_lengths(value.game_version) {
+        if value.upgrades.len() != upgrades {
+            violations.push(Violation::UpgradesLength {
+                expected: upgrades,
+                actual: value.upgrades.len(),
+            });
+        }
+        if value.achievements.len() != achievements {
+            violations.push(Violation::AchievementsLength {
+                expected: achievements,
+                actual: value.achievements.len(),
+            });
+        }
+    }
+    for &id in &misc.upgrades_in_vault {
+        if id >= value.upgrades.len() {
+            violations.push(Violation::UnknownVaultUpgrade { id });
+        }
+    }
+
+    if let Some(garden) = &value.building_data.farms.minigame_data {
+        let expected = Garden::WIDTH * Garden::HEIGHT;
+        if garden.farm_grid_data.len() != expected {
+            violations.push(Violation::GardenSize {
+                expected,
+                actual: garden.farm_grid_data.len(),
+            });
+        }
+        for (i, tile) in garden.farm_grid_data.iter().enumerate() {
+            let Some(tile) = tile else {
+                continue;
+            };
+            let (x, y) = (i % Garden::WIDTH, i / Garden::WIDTH);
+            if tile.plant().is_none() {
+                violations.push(Violation::UnknownPlant { x, y, id: tile.id });
+            } else if tile.age >= 100 {
+                violations.push(Violation::PlantTooOld {
+                    x,
+                    y,
+                    age: tile.age,
+                });
+            }
+        }
+    }
+
+    if let Some(pantheon) = &value.building_data.temples.minigame_data {
+        let mut seen = HashSet::new();
+        for (slot, spirit) in pantheon.slots.iter().enumerate() {
+            if let Some(spirit) = spirit {
+                if !seen.insert(*spirit) {
+                    violations.push(Violation::DuplicateSpirit { slot });
+                }
+            }
+        }
+    }
+
+    violations.extend(version::check_layout(value));
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Severity, Violation};
+    use crate::Building;
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_validate() {
+        for value in [
+            include_str!("samples/00.txt"),
+            include_str!("samples/01.txt"),
+            include_str!("samples/02.txt"),
+        ] {
+            let save = crate::decode(value.trim()).unwrap();
+            assert_eq!(save.validate(), []);
+        }
+
+        let mut save = crate::decode(include_str!("samples/00.txt").trim()).unwrap();
+        save.building_data.cursors.amount_owned = save.building_data.cursors.highest_amount + 1;
+        save.miscellaneous_game_data.heavenly_chips_spent = f64::INFINITY;
+        save.achievements.pop();
+        let violations = save.validate();
+        assert!(matches!(
+            violations[..],
+            [
+                Violation::InvalidAmount {
+                    field: "heavenly_chips_spent",
+                    ..
+                },
+                Violation::HeavenlyChipsOverspent { .. },
+                Violation::OwnedExceedsHighest {
+                    building: Building::Cursor,
+                    ..
+                },
+                Violation::AchievementsLength {
+                    expected: 643,
+                    actual: 642,
+                },
+            ],
+        ));
+        assert_eq!(violations[2].severity(), Severity::Warning);
+        assert_eq!(violations[3].severity(), Severity::Error);
+
+        let mut save = crate::decode(include_str!("samples/01.txt").trim()).unwrap();
+        let misc = &mut save.miscellaneous_game_data;
+        misc.prestive_level = 1e20;
+        misc.heavenly_chips = 1e4;
+        misc.heavenly_chips_spent = 1e20;
+        assert_ne!(
+            misc.heavenly_chips + misc.heavenly_chips_spent,
+            misc.prestive_level
+        );
+        assert_eq!(save.validate(), []);
+
+        save.miscellaneous_game_data.heavenly_chips_spent = 1.5e20;
+        assert_eq!(
+            save.validate()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["150000000000000000000 heavenly chips spent, \
+              more than the prestige level 100000000000000000000"],
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::error::Error;
+use crate::format;
+use crate::{Save, Upgrade, Violation};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+/// A game version such as `2.052` or `1.0466`, as written by `Game.version`.
+/// It serializes as its text, e.g. `"2.052"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GameVersion {
+    pub major: u32,
+    /// in ten-thousandths, so that `2.04` is `400`, `2.052` is `520` and `1.0466` is `466`
+    pub minor: u32,
+}
+
+impl GameVersion {
+    pub const V2_019: Self = Self::new(2, 190);
+    pub const V2_030: Self = Self::new(2, 300);
+    pub const V2_040: Self = Self::new(2, 400);
+    pub const V2_045: Self = Self::new(2, 450);
+    pub const V2_048: Self = Self::new(2, 480);
+    pub const V2_052: Self = Self::new(2, 520);
+
+    /// The most recent layout this crate knows about.
+    pub const LATEST: Self = Self::V2_052;
+
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl Default for GameVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl FromStr for GameVersion {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Error> {
+        let (major, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if fraction.len() > 4 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidData);
+        }
+        let minor = format!("{fraction:0<4}").parse()?;
+        Ok(Self::new(major.parse()?, minor))
+    }
+}
+
+// Mirrors JavaScript's number formatting: `2`, `2.04`, `2.052`, `1.0466`.
+impl fmt::Display for GameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.major)?;
+        if self.minor != 0 {
+            let minor = format!("{:04}", self.minor);
+            write!(f, ".{}", minor.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for GameVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <Cow<'_, str>>::deserialize(deserializer)?;
+        value
+            .parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&value), &"a game version"))
+    }
+}
+
+impl format::Format<'_, GameVersion> for format::Standard {
+    fn decode(value: &str) -> Result<GameVersion, Error> {
+        value.parse()
+    }
+
+    fn encode(value: &GameVersion, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{value}")
+    }
+}
+
+// Fields added over the 2.x releases. All of them trail their section, so that older saves
+// simply end early; see `format::Extra`.
+const RUN_DETAILS: &[(&str, GameVersion)] = &[("you_appearance", GameVersion::V2_052)];
+const PREFERENCES: &[(&str, GameVersion)] = &[
+    ("enable_cloud_saving", GameVersion::V2_040),
+    ("music_in_background", GameVersion::V2_040),
+    ("scary_stuff_off", GameVersion::V2_040),
+    ("fullscreen", GameVersion::V2_040),
+    ("screan_reader", GameVersion::V2_045),
+    ("todo0", GameVersion::V2_052),
+];
+const MISCELLANEOUS_GAME_DATA: &[(&str, GameVersion)] = &[
+    ("music_volume", GameVersion::V2_040),
+    ("cookies_sent", GameVersion::V2_048),
+    ("cookies_received", GameVersion::V2_048),
+];
+const BUILDING_DATA: &[(&str, GameVersion)] = &[
+    ("javascript_consoles", GameVersion::V2_019),
+    ("idleverses", GameVersion::V2_030),
+    ("cortex_bakers", GameVersion::V2_048),
+    ("yous", GameVersion::V2_052),
+];
+
+// The lengths of `Save::upgrades` and `Save::achievements`, one entry per upgrade and
+// achievement the version defines. Versions without an entry are not checked.
+const LIST_LENGTHS: &[(GameVersion, usize, usize)] = &[(GameVersion::V2_052, 875, 643)];
+
+/// The lengths of the upgrade and achievement lists that `version` writes, where known.
+pub(crate) fn list_lengths(version: GameVersion) -> Option<(usize, usize)> {
+    LIST_LENGTHS
+        .iter()
+        .find(|(v, ..)| *v == version)
+        .map(|&(_, upgrades, achievements)| (upgrades, achievements))
+}
+
+/// Checks the records of `value` against the layout of its `game_version`: the fields the
+/// version has should be present, and the ones added later absent.
+pub(crate) fn check_layout(value: &Save) -> Vec<Violation> {
+    let version = value.game_version;
+    let mut violations = Vec::new();
+    let mut check = |section, extra: &format::Extra, fields: &[(&'static str, GameVersion)]| {
+        for &(field, since) in fields {
+            let missing = extra.is_defaulted(field) || extra.is_omitted(field);
+            if missing == (since <= version) {
+                violations.push(Violation::LayoutMismatch {
+                    section,
+                    field,
+                    version,
+                    missing,
+                });
+            }
+        }
+    };
+    check("run_details", &value.run_details.extra, RUN_DETAILS);
+    check("preferences", &value.preferences.extra, PREFERENCES);
+    check(
+        "miscellaneous_game_data",
+        &value.miscellaneous_game_data.extra,
+        MISCELLANEOUS_GAME_DATA,
+    );
+    check("building_data", &value.building_data.extra, BUILDING_DATA);
+    violations
+}
+
+/// Rewrites the layout bookkeeping of `value` so that it encodes as `version` would: the
+/// trailing fields of the records, and the lengths of the upgrade and achievement lists where
+/// `list_lengths` knows them. The lists are kept as they are for other versions, and so is the
+/// minigame data, whose layouts are not tracked.
+pub(crate) fn retarget(value: &mut Save, version: GameVersion) {
+    // Unknown fields come from a newer version, so they only survive an upgrade.
+    let keep_unknown = version >= value.game_version;
+    let apply = |extra: &mut format::Extra, fields: &[(&str, GameVersion)]| {
+        if !keep_unknown {
+            extra.unknown = None;
+        }
+        extra.defaulted.clear();
+        extra.omitted = fields
+            .iter()
+            .filter(|(_, since)| *since > version)
+            .map(|(field, _)| field.to_string())
+            .collect();
+    };
+    apply(&mut value.run_details.extra, RUN_DETAILS);
+    apply(&mut value.preferences.extra, PREFERENCES);
+    apply(
+        &mut value.miscellaneous_game_data.extra,
+        MISCELLANEOUS_GAME_DATA,
+    );
+    apply(&mut value.building_data.extra, BUILDING_DATA);
+    if let Some((upgrades, achievements)) = list_lengths(version) {
+        value.upgrades.resize(
+            upgrades,
+            Upgrade {
+                unlocked: false,
+                bought: false,
+            },
+        );
+        value.achievements.resize(achievements, false);
+    }
+    value.game_version = version;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GameVersion;
+    use crate::{escape, format, Severity, Violation};
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_game_version() {
+        let value: GameVersion = "2.04".parse().unwrap();
+        assert_eq!(value, GameVersion::V2_040);
+        assert!(value < "2.052".parse().unwrap());
+        assert!(value > "2.031".parse().unwrap());
+        assert!("2.05201".parse::<GameVersion>().is_err());
+        assert!("1.0466".parse::<GameVersion>().unwrap() < "2".parse().unwrap());
+        for value in ["2", "2.04", "2.031", "2.052", "1.0466", "1.9"] {
+            format::check_inverse::<'_, '_, format::Standard, GameVersion>(value).unwrap();
+        }
+
+        assert_eq!(serde_json::to_value(GameVersion::V2_052).unwrap(), "2.052");
+        let value: GameVersion = serde_json::from_str(r#""1.0466""#).unwrap();
+        assert_eq!(value, GameVersion::new(1, 466));
+        assert!(serde_json::from_str::<GameVersion>(r#""2.05x""#).is_err());
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_layout() {
+        let value = escape::decode(include_str!("samples/00.txt").trim()).unwrap();
+        let older = value.replacen("2.052|", "2.031|", 1);
+        let save = crate::decode(&escape::encode(&older)).unwrap();
+        let violations = super::check_layout(&save);
+        assert_eq!(
+            violations[0].to_string(),
+            "run_details.you_appearance: unexpected in version 2.031"
+        );
+        assert!(violations
+            .iter()
+            .all(|v| matches!(v, Violation::LayoutMismatch { missing: false, .. })));
+        assert_eq!(
+            violations
+                .iter()
+                .filter(|v| v.severity() == Severity::Warning)
+                .count(),
+            violations.len()
+        );
+        assert_eq!(save.validate(), violations);
+
+        let save = crate::decode(include_str!("samples/00.txt").trim()).unwrap();
+        let older = crate::encode_for(&save, "2.031".parse().unwrap());
+        let older = crate::decode(&older).unwrap();
+        assert_eq!(super::check_layout(&older), []);
+        // The list lengths of 2.031 are unknown, and minigame layouts are not tracked.
+        assert_eq!(older.upgrades.len(), save.upgrades.len());
+        assert_eq!(older.achievements.len(), save.achievements.len());
+        assert_eq!(
+            serde_json::to_value(&older.building_data.farms.minigame_data).unwrap(),
+            serde_json::to_value(&save.building_data.farms.minigame_data).unwrap()
+        );
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_list_lengths() {
+        let mut save = crate::decode(include_str!("samples/00.txt").trim()).unwrap();
+        save.upgrades.truncate(800);
+        save.achievements.push(true);
+        let save = crate::decode(&crate::encode_for(&save, GameVersion::V2_052)).unwrap();
+        assert_eq!(save.upgrades.len(), 875);
+        assert!(!save.upgrades[874].unlocked && !save.upgrades[874].bought);
+        assert_eq!(save.achievements.len(), 643);
+        assert_eq!(save.validate(), []);
+    }
+}